@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::path::Path;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use rs_advent_of_code_2022::registry::{self, DAYS, PARTS};
+use rs_advent_of_code_2022::y2022::day3::{self, Rucksack, GROUP_SIZE};
+
+fn read_input(year: u32, day: u32) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(registry::input_path(year, day));
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+}
+
+// one group per day, so alternative implementations can be added to a group and compared
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS {
+        let input = read_input(day.year(), day.day());
+        let input = registry::prepare_input(&input);
+        let parsed = day.parse(input).expect("failed to parse input");
+
+        let mut group = c.benchmark_group(format!("{}/day{}", day.year(), day.day()));
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(input))));
+        for part in PARTS {
+            group.bench_function(format!("part{}", part), |b| {
+                b.iter(|| parsed.part(black_box(part)))
+            });
+        }
+        group.finish();
+    }
+}
+
+// the previous `HashSet` based solutions of day 3, kept to compare them against `ItemSet`
+fn day3_hash_set_part1(rucksacks: &[Rucksack]) -> Option<u32> {
+    rucksacks
+        .iter()
+        .map(|x| {
+            let set: HashSet<_> = x.comp1().iter().collect();
+            x.comp2()
+                .iter()
+                .find(|item| set.contains(item))
+                .map(|item| item.score())
+        })
+        .sum()
+}
+
+fn day3_hash_set_part2(rucksacks: &[Rucksack]) -> Option<u32> {
+    rucksacks
+        .chunks_exact(GROUP_SIZE)
+        .map(|group| {
+            let sets: Vec<HashSet<_>> = group
+                .iter()
+                .map(|x| x.comp1().iter().chain(x.comp2().iter()).collect())
+                .collect();
+            let intersection = sets.iter().skip(1).fold(sets[0].clone(), |acc, set| {
+                acc.intersection(set).copied().collect()
+            });
+            intersection.into_iter().next().map(|item| item.score())
+        })
+        .sum()
+}
+
+fn bench_day3_item_sets(c: &mut Criterion) {
+    let input = read_input(2022, 3);
+    let input = registry::prepare_input(&input);
+    let parsed = day3::parse(input).expect("failed to parse input");
+    assert_eq!(day3::part1(&parsed).ok(), day3_hash_set_part1(&parsed));
+    assert_eq!(day3::part2(&parsed).ok(), day3_hash_set_part2(&parsed));
+
+    let mut group = c.benchmark_group("2022/day3 item sets");
+    group.bench_function("part1/hash_set", |b| {
+        b.iter(|| day3_hash_set_part1(black_box(&parsed)))
+    });
+    group.bench_function("part1/item_set", |b| {
+        b.iter(|| day3::part1(black_box(&parsed)))
+    });
+    group.bench_function("part2/hash_set", |b| {
+        b.iter(|| day3_hash_set_part2(black_box(&parsed)))
+    });
+    group.bench_function("part2/item_set", |b| {
+        b.iter(|| day3::part2(black_box(&parsed)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_days, bench_day3_item_sets);
+criterion_main!(benches);
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::AocError;
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Toml(toml::de::Error),
+    InvalidKey(String),
+    InvalidAnswer(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Toml(err) => write!(f, "{}", err),
+            AnswersError::InvalidKey(key) => write!(
+                f,
+                "invalid key `{}`, expected `[<year>.day<N>]` tables with `part<N>` entries",
+                key
+            ),
+            AnswersError::InvalidAnswer(key) => {
+                write!(f, "answer `{}` has to be a string or an integer", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl From<toml::de::Error> for AnswersError {
+    fn from(err: toml::de::Error) -> Self {
+        AnswersError::Toml(err)
+    }
+}
+
+// recorded answers keyed by (year, day, part), read from files like
+//
+// [2022.day1]
+// part1 = "66306"
+// part2 = 195292
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u32, u32, u32), String>);
+
+fn parse_key(key: &str, prefix: &str) -> Result<u32, AnswersError> {
+    key.strip_prefix(prefix)
+        .and_then(|x| x.parse().ok())
+        .ok_or_else(|| AnswersError::InvalidKey(key.to_string()))
+}
+
+fn as_table<'a>(key: &str, value: &'a toml::Value) -> Result<&'a toml::Table, AnswersError> {
+    value
+        .as_table()
+        .ok_or_else(|| AnswersError::InvalidKey(key.to_string()))
+}
+
+impl Answers {
+    pub fn from_toml(input: &str) -> Result<Self, AnswersError> {
+        let table: toml::Table = toml::from_str(input)?;
+
+        let mut answers = BTreeMap::new();
+        for (year_key, days) in &table {
+            let year = parse_key(year_key, "")?;
+            for (day_key, parts) in as_table(year_key, days)? {
+                let day = parse_key(day_key, "day")?;
+                for (part_key, answer) in as_table(day_key, parts)? {
+                    let part = parse_key(part_key, "part")?;
+                    let answer = match answer {
+                        toml::Value::String(x) => x.clone(),
+                        toml::Value::Integer(x) => x.to_string(),
+                        _ => return Err(AnswersError::InvalidAnswer(part_key.clone())),
+                    };
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+    Error(String),
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: Result<String, AocError>) -> Self {
+        match (expected, actual) {
+            (_, Err(err)) => Verdict::Error(err.to_string()),
+            (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+            (None, Ok(actual)) => Verdict::Missing { actual },
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Verdict::Missing { actual } => write!(f, "missing (got {})", actual),
+            Verdict::Error(err) => write!(f, "ERROR ({})", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "
+[2022.day1]
+part1 = \"24000\"
+part2 = 45000
+
+[2022.day5]
+part1 = \"CMZ\"
+";
+
+    #[test]
+    fn test_from_toml() {
+        let answers = Answers::from_toml(TEST_INPUT).unwrap();
+
+        assert_eq!(Some("24000"), answers.get(2022, 1, 1));
+        assert_eq!(Some("45000"), answers.get(2022, 1, 2));
+        assert_eq!(Some("CMZ"), answers.get(2022, 5, 1));
+        assert_eq!(None, answers.get(2022, 5, 2));
+        assert_eq!(None, answers.get(2021, 1, 1));
+    }
+
+    #[test]
+    fn test_from_toml_invalid_key() {
+        assert!(matches!(
+            Answers::from_toml("[2022.one]\npart1 = \"1\""),
+            Err(AnswersError::InvalidKey(key)) if key == "one"
+        ));
+        assert!(matches!(
+            Answers::from_toml("[2022.day1]\npart1 = 1.5"),
+            Err(AnswersError::InvalidAnswer(key)) if key == "part1"
+        ));
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::Pass, Verdict::new(Some("1"), Ok("1".to_string())));
+        assert!(Verdict::new(Some("1"), Ok("2".to_string())).is_regression());
+        assert!(!Verdict::new(None, Ok("2".to_string())).is_regression());
+        assert!(Verdict::new(Some("1"), Err(AocError::NoSolution("none"))).is_regression());
+    }
+}
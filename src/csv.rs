@@ -0,0 +1,33 @@
+// quotes fields containing separators, quotes or line breaks, doubling the quotes inside
+pub fn field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// a line of quoted fields, including the line break
+pub fn record<I>(fields: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let fields: Vec<_> = fields.into_iter().map(|x| field(x.as_ref())).collect();
+    fields.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        assert_eq!("a,1\n", record(["a", "1"]));
+        assert_eq!(
+            "\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\n",
+            record(["a,b", "say \"hi\"", "two\nlines"])
+        );
+        assert_eq!("\n", record::<[&str; 0]>([]));
+    }
+}
@@ -1,16 +1,37 @@
-// #[aoc_generator(day2)]
-pub fn parse(input: &str) -> &str {
-    input
+use crate::error::AocError;
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Result<String, AocError> {
+    Ok(input.to_string())
+}
+
+pub fn part1(input: &str) -> Result<usize, AocError> {
+    Ok(input.lines().count())
 }
 
-#[aoc(day2, part1)]
-pub fn part1(input: &str) -> usize {
-    0
+pub fn part2(input: &str) -> Result<usize, AocError> {
+    Ok(input.lines().count())
 }
 
-#[aoc(day2, part2)]
-pub fn part2(input: &str) -> usize {
-    0
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    const YEAR: u32 = __YEAR__;
+    const DAY: u32 = __DAY__;
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -21,16 +42,16 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        assert_eq!("a", parse(TEST_INPUT));
+        assert_eq!("", parse(TEST_INPUT).unwrap());
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(1, part1(&parse(TEST_INPUT)));
+        assert_eq!(0, part1(&parse(TEST_INPUT).unwrap()).unwrap());
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(1, part2(&parse(TEST_INPUT)));
+        assert_eq!(0, part2(&parse(TEST_INPUT).unwrap()).unwrap());
     }
 }
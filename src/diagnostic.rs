@@ -0,0 +1,152 @@
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub span: Range<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub found: String,
+    pub expected: String,
+}
+
+impl Diagnostic {
+    // `found` is expected to be a slice of `input`, otherwise its first occurrence is used
+    pub fn new(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let start = offset_in(input, found)
+            .or_else(|| input.find(found))
+            .unwrap_or(input.len());
+
+        let before = &input[..start];
+        let line_start = before.rfind('\n').map_or(0, |x| x + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |x| start + x);
+
+        Diagnostic {
+            span: start..start + found.len(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    // moves a diagnostic created for a chunk of a larger input, e.g. a single line read from a
+    // stream, to its position in the whole input
+    pub fn shifted(mut self, lines: usize, bytes: usize) -> Self {
+        self.line += lines;
+        self.span = self.span.start + bytes..self.span.end + bytes;
+        self
+    }
+
+    pub fn render(&self, message: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "error: {}\n{} --> line {}, column {}\n{} |\n{} | {}\n{} | {}{} expected {}, found {}",
+            message,
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            self.source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.found.chars().count().max(1)),
+            self.expected,
+            self.found_description()
+        )
+    }
+
+    fn found_description(&self) -> String {
+        if self.found.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("`{}`", self.found)
+        }
+    }
+}
+
+fn offset_in(input: &str, text: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize).checked_sub(start)?;
+    (offset + text.len() <= input.len()).then_some(offset)
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line,
+            self.column,
+            self.expected,
+            self.found_description()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let input = "1000\n2000\n3x00";
+        let line = input.lines().nth(2).unwrap();
+
+        let diagnostic = Diagnostic::new(input, &line[1..2], "a number");
+        assert_eq!(11..12, diagnostic.span);
+        assert_eq!(3, diagnostic.line);
+        assert_eq!(2, diagnostic.column);
+        assert_eq!("3x00", diagnostic.source_line);
+        assert_eq!("x", diagnostic.found);
+    }
+
+    #[test]
+    fn test_shifted() {
+        let line = "3x00";
+        let diagnostic = Diagnostic::new(line, &line[1..2], "a number").shifted(2, 10);
+
+        assert_eq!(11..12, diagnostic.span);
+        assert_eq!(3, diagnostic.line);
+        assert_eq!(2, diagnostic.column);
+        assert_eq!("3x00", diagnostic.source_line);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "move 1 from 2 to 1\nmove 3 from one to 3";
+        let diagnostic = Diagnostic::new(input, &input[31..34], "a stack number");
+
+        assert_eq!(
+            "\
+error: invalid instruction
+  --> line 2, column 13
+  |
+2 | move 3 from one to 3
+  |             ^^^ expected a stack number, found `one`",
+            diagnostic.render("invalid instruction")
+        );
+    }
+
+    #[test]
+    fn test_render_end_of_line() {
+        let input = "A";
+        let diagnostic = Diagnostic::new(input, &input[1..], "`<opponent> <player>`");
+
+        assert_eq!(
+            "\
+error: invalid format
+  --> line 1, column 2
+  |
+1 | A
+  |  ^ expected `<opponent> <player>`, found end of line",
+            diagnostic.render("invalid format")
+        );
+    }
+}
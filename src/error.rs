@@ -0,0 +1,128 @@
+use std::fmt;
+
+use crate::diagnostic::Diagnostic;
+use crate::y2022::day2::RockPaperScissorsParseError;
+use crate::y2022::{day1, day3, day4, day5};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AocError {
+    Calories(Diagnostic, day1::ParseError),
+    RockPaperScissors(Diagnostic, RockPaperScissorsParseError),
+    Rucksack(Diagnostic, day3::ParseError),
+    Range(Diagnostic, day4::ParseError),
+    Instruction(Diagnostic, day5::InstructionParseError),
+    InvalidFormat(Diagnostic),
+    Io(String),
+    Overflow(day1::OverflowError),
+    Badge(day3::BadgeError),
+    NoSolution(&'static str),
+}
+
+impl AocError {
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        match self {
+            AocError::Calories(diagnostic, _)
+            | AocError::RockPaperScissors(diagnostic, _)
+            | AocError::Rucksack(diagnostic, _)
+            | AocError::Range(diagnostic, _)
+            | AocError::Instruction(diagnostic, _)
+            | AocError::InvalidFormat(diagnostic) => Some(diagnostic),
+            AocError::Io(_)
+            | AocError::Overflow(_)
+            | AocError::Badge(_)
+            | AocError::NoSolution(_) => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            AocError::Calories(_, err) => err.to_string(),
+            AocError::RockPaperScissors(_, err) => err.to_string(),
+            AocError::Rucksack(_, err) => err.to_string(),
+            AocError::Range(_, err) => err.to_string(),
+            AocError::Instruction(_, err) => err.to_string(),
+            AocError::InvalidFormat(_) => "invalid format".to_string(),
+            AocError::Io(err) => format!("failed to read input: {}", err),
+            AocError::Overflow(err) => err.to_string(),
+            AocError::Badge(err) => err.to_string(),
+            AocError::NoSolution(reason) => format!("no solution: {}", reason),
+        }
+    }
+}
+
+// the alternate form `{:#}` renders a snippet of the offending input
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.diagnostic() {
+            Some(diagnostic) if f.alternate() => {
+                write!(f, "{}", diagnostic.render(&self.message()))
+            }
+            Some(diagnostic) => write!(f, "{} at {}", self.message(), diagnostic),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+pub trait LocatedError {
+    fn at(self, diagnostic: Diagnostic) -> AocError;
+}
+
+impl LocatedError for day1::ParseError {
+    fn at(self, diagnostic: Diagnostic) -> AocError {
+        AocError::Calories(diagnostic, self)
+    }
+}
+
+impl LocatedError for RockPaperScissorsParseError {
+    fn at(self, diagnostic: Diagnostic) -> AocError {
+        AocError::RockPaperScissors(diagnostic, self)
+    }
+}
+
+impl LocatedError for day3::ParseError {
+    fn at(self, diagnostic: Diagnostic) -> AocError {
+        AocError::Rucksack(diagnostic, self)
+    }
+}
+
+impl LocatedError for day4::ParseError {
+    fn at(self, diagnostic: Diagnostic) -> AocError {
+        AocError::Range(diagnostic, self)
+    }
+}
+
+impl LocatedError for day5::InstructionParseError {
+    fn at(self, diagnostic: Diagnostic) -> AocError {
+        AocError::Instruction(diagnostic, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let input = "A Y\nB Q";
+        let err = AocError::RockPaperScissors(
+            Diagnostic::new(input, &input[6..], "one of `X`, `Y`, `Z`"),
+            RockPaperScissorsParseError,
+        );
+
+        assert_eq!(
+            "invalid rock paper scissors symbol at line 2, column 3: expected one of `X`, `Y`, `Z`, found `Q`",
+            err.to_string()
+        );
+        assert_eq!(
+            "\
+error: invalid rock paper scissors symbol
+  --> line 2, column 3
+  |
+2 | B Q
+  |   ^ expected one of `X`, `Y`, `Z`, found `Q`",
+            format!("{:#}", err)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const CACHE_DIR: &str = "input";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(PathBuf, io::Error),
+    MissingSession,
+    Http(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            InputError::MissingSession => write!(
+                f,
+                "input is not cached and AOC_SESSION is not set to fetch it"
+            ),
+            InputError::Http(err) => write!(f, "failed to fetch input: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn cache_path(cache_dir: &Path, year: u32, day: u32) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{}.txt", day))
+}
+
+pub trait Fetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, InputError>;
+}
+
+pub struct HttpFetcher {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    // uses the session token in AOC_SESSION and the base url in AOC_BASE_URL, if set
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        let session = std::env::var("AOC_SESSION").ok();
+        HttpFetcher::new(&base_url, session)
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let session = self.session.as_ref().ok_or(InputError::MissingSession)?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set(
+                "User-Agent",
+                "github.com/deify/rs_advent_of_code_2022 input cache",
+            )
+            .call()
+            .map_err(|e| InputError::Http(e.to_string()))?
+            .into_string()
+            .map_err(|e| InputError::Http(e.to_string()))
+    }
+}
+
+pub struct InputProvider<F: Fetcher> {
+    cache_dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputProvider<F> {
+    pub fn new(cache_dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        InputProvider {
+            cache_dir: cache_dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        cache_path(&self.cache_dir, year, day)
+    }
+
+    // empty files, like the ones created by `new-day`, count as not cached
+    pub fn get(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.is_empty() => return Ok(input),
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(InputError::Io(path, e)),
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+        store(&path, &input)?;
+        Ok(input)
+    }
+}
+
+// writes to a temporary file next to `path` first, so readers never see a partial input
+fn store(path: &Path, content: &str) -> Result<(), InputError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |e| InputError::Io(path, e)
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error(dir))?;
+    }
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    fs::write(&tmp, content).map_err(io_error(&tmp))?;
+    fs::rename(&tmp, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        InputError::Io(path.to_path_buf(), e)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::*;
+
+    // answers `responses.len()` requests in order and reports the request lines and cookies
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut cookie = String::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    match header.split_once(':') {
+                        Some((name, value)) if name.eq_ignore_ascii_case("cookie") => {
+                            cookie = value.trim().to_string()
+                        }
+                        _ => {}
+                    }
+                }
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender
+                    .send((request_line.trim().to_string(), cookie))
+                    .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (base_url, requests) = serve(vec![(200, "1000\n2000\n")]);
+        let cache_dir = temp_dir("fetch");
+        let provider = InputProvider::new(
+            &cache_dir,
+            HttpFetcher::new(&base_url, Some("secret".to_string())),
+        );
+
+        assert_eq!("1000\n2000\n", provider.get(2022, 1).unwrap());
+        assert_eq!(
+            (
+                "GET /2022/day/1/input HTTP/1.1".to_string(),
+                "session=secret".to_string()
+            ),
+            requests.recv().unwrap()
+        );
+        assert_eq!(
+            "1000\n2000\n",
+            fs::read_to_string(cache_dir.join("2022/day1.txt")).unwrap()
+        );
+
+        // the stand-in only answers once, so this has to come from the cache
+        assert_eq!("1000\n2000\n", provider.get(2022, 1).unwrap());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_is_not_cached() {
+        let (base_url, _requests) = serve(vec![(404, "not found")]);
+        let cache_dir = temp_dir("error");
+        let provider = InputProvider::new(
+            &cache_dir,
+            HttpFetcher::new(&base_url, Some("secret".to_string())),
+        );
+
+        assert!(matches!(provider.get(2022, 2), Err(InputError::Http(_))));
+        assert!(!provider.path(2022, 2).exists());
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let cache_dir = temp_dir("session");
+        let provider = InputProvider::new(&cache_dir, HttpFetcher::new(DEFAULT_BASE_URL, None));
+
+        assert!(matches!(
+            provider.get(2022, 3),
+            Err(InputError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn test_cached_input_is_used() {
+        struct NoFetch;
+        impl Fetcher for NoFetch {
+            fn fetch(&self, _: u32, _: u32) -> Result<String, InputError> {
+                panic!("input should come from the cache")
+            }
+        }
+
+        let cache_dir = temp_dir("cached");
+        let provider = InputProvider::new(&cache_dir, NoFetch);
+        store(&provider.path(2022, 4), "2-4,6-8").unwrap();
+
+        assert_eq!("2-4,6-8", provider.get(2022, 4).unwrap());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt::{self, Debug, Display};
+use std::ops::{Range, RangeInclusive};
+
+pub trait Integer: Copy + Ord + Debug + Display {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+    // the number of integers in `start..=end`, for `start <= end`
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+// a set of consecutive integers, stored with inclusive bounds so it can reach the maximum of
+// `T`, all empty intervals are equal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn empty() -> Self {
+        Interval {
+            start: T::MAX,
+            end: T::MIN,
+        }
+    }
+
+    // `start..=end`, empty if `start > end`
+    pub fn closed(start: T, end: T) -> Self {
+        if start > end {
+            Interval::empty()
+        } else {
+            Interval { start, end }
+        }
+    }
+
+    // `start..end`, empty if `start >= end`
+    pub fn half_open(start: T, end: T) -> Self {
+        match end.checked_pred() {
+            Some(last) if start < end => Interval::closed(start, last),
+            _ => Interval::empty(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    // the number of integers in the interval
+    pub fn len(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            T::count(self.start, self.end)
+        }
+    }
+
+    pub fn start(&self) -> Option<T> {
+        (!self.is_empty()).then_some(self.start)
+    }
+
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then_some(self.end)
+    }
+
+    pub fn to_range_inclusive(&self) -> Option<RangeInclusive<T>> {
+        (!self.is_empty()).then_some(self.start..=self.end)
+    }
+
+    // none if the interval is empty or its end is not representable because it contains the
+    // maximum of `T`
+    pub fn to_range(&self) -> Option<Range<T>> {
+        let end = self.end.checked_succ()?;
+        (!self.is_empty()).then_some(self.start..end)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.is_empty() || (other.start <= self.start && self.end <= other.end)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // both intervals are non-empty and disjoint, but there is no integer between them
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        let touches = |a: &Self, b: &Self| a.end.checked_succ() == Some(b.start);
+        !self.is_empty() && !other.is_empty() && (touches(self, other) || touches(other, self))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Interval::closed(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // the smallest interval containing both intervals
+    pub fn hull(&self, other: &Self) -> Self {
+        match (self.is_empty(), other.is_empty()) {
+            (true, _) => *other,
+            (_, true) => *self,
+            _ => Interval::closed(self.start.min(other.start), self.end.max(other.end)),
+        }
+    }
+
+    // none if there are integers between the intervals, so the union is not an interval
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let hull = self.hull(other);
+        (hull.len() == self.len() + other.len() - self.intersection(other).len()).then_some(hull)
+    }
+
+    // the integers of `self` not in `other`, as the parts below and above `other`, either of
+    // them can be empty, all of `self` is below an empty `other`
+    pub fn difference(&self, other: &Self) -> (Self, Self) {
+        if !self.overlaps(other) {
+            return if other.is_empty() || self.end < other.start {
+                (*self, Interval::empty())
+            } else {
+                (Interval::empty(), *self)
+            };
+        }
+        let before = match other.start.checked_pred() {
+            Some(x) => Interval::closed(self.start, x),
+            None => Interval::empty(),
+        };
+        let after = match other.end.checked_succ() {
+            Some(x) => Interval::closed(x, self.end),
+            None => Interval::empty(),
+        };
+        (before, after)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::closed(*range.start(), *range.end())
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::half_open(range.start, range.end)
+    }
+}
+
+impl<T: Integer> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "empty")
+        } else {
+            write!(f, "{}..={}", self.start, self.end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(Interval::closed(2, 4), Interval::half_open(2, 5));
+        assert_eq!(Interval::closed(2, 4), Interval::from(2..=4));
+        assert_eq!(Interval::closed(2, 4), Interval::from(2..5));
+        assert_eq!(Interval::<i32>::empty(), Interval::closed(5, 4));
+        assert_eq!(Interval::<i32>::empty(), Interval::half_open(4, 4));
+        assert_eq!(Interval::<u8>::empty(), Interval::half_open(0, 0));
+        assert_eq!(Some(2..5), Interval::closed(2, 4).to_range());
+        assert_eq!(Some(2..=4), Interval::closed(2, 4).to_range_inclusive());
+        assert_eq!("2..=4", Interval::closed(2, 4).to_string());
+        assert_eq!("empty", Interval::<u8>::empty().to_string());
+    }
+
+    #[test]
+    fn test_bounds_of_type() {
+        let full = Interval::closed(u8::MIN, u8::MAX);
+
+        assert_eq!(256, full.len());
+        assert_eq!(None, full.to_range());
+        assert_eq!(Some(255), full.last());
+        assert_eq!(
+            (Interval::closed(0, 9), Interval::closed(21, 255)),
+            full.difference(&Interval::closed(10, 20))
+        );
+        assert_eq!(
+            (Interval::empty(), Interval::empty()),
+            Interval::closed(250, 255).difference(&full)
+        );
+        assert_eq!(1 << 64, Interval::closed(i64::MIN, i64::MAX).len());
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = Interval::closed(2, 6);
+        let b = Interval::closed(4, 8);
+        let c = Interval::closed(7, 9);
+
+        assert_eq!(Interval::closed(4, 6), a.intersection(&b));
+        assert_eq!(Some(Interval::closed(2, 8)), a.union(&b));
+        assert_eq!(Some(Interval::closed(2, 9)), a.union(&c));
+        assert_eq!(None, a.union(&Interval::closed(8, 9)));
+        assert!(a.is_adjacent(&c));
+        assert!(!a.is_adjacent(&b));
+        assert!(Interval::closed(3, 5).is_subset(&a));
+        assert!(Interval::empty().is_subset(&a));
+        assert_eq!(
+            (Interval::closed(2, 3), Interval::closed(5, 6)),
+            a.difference(&Interval::closed(4, 4))
+        );
+        assert_eq!(
+            (Interval::empty(), Interval::closed(7, 9)),
+            c.difference(&Interval::closed(2, 4))
+        );
+        assert_eq!(5, a.len());
+    }
+
+    fn interval() -> impl Strategy<Value = Interval<i32>> {
+        (-20..20, -20..20).prop_map(|(a, b)| Interval::closed(a, b))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_len(a in interval()) {
+            prop_assert_eq!(a.len(), (-25..25).filter(|x| a.contains(*x)).count() as u128);
+            prop_assert_eq!(a.is_empty(), !(-25..25).any(|x| a.contains(x)));
+        }
+
+        #[test]
+        fn prop_intersection(a in interval(), b in interval(), x in -25..25) {
+            let c = a.intersection(&b);
+            prop_assert_eq!(c.contains(x), a.contains(x) && b.contains(x));
+            prop_assert_eq!(a.overlaps(&b), !c.is_empty());
+            prop_assert!(c.is_subset(&a) && c.is_subset(&b));
+        }
+
+        #[test]
+        fn prop_union(a in interval(), b in interval(), x in -25..25) {
+            match a.union(&b) {
+                Some(c) => prop_assert_eq!(c.contains(x), a.contains(x) || b.contains(x)),
+                None => prop_assert!(!a.overlaps(&b) && !a.is_adjacent(&b)),
+            }
+            let touching = a.overlaps(&b) || a.is_adjacent(&b) || a.is_empty() || b.is_empty();
+            prop_assert_eq!(touching, a.union(&b).is_some());
+            prop_assert!(a.is_subset(&a.hull(&b)) && b.is_subset(&a.hull(&b)));
+        }
+
+        #[test]
+        fn prop_difference(a in interval(), b in interval(), x in -25..25) {
+            let (before, after) = a.difference(&b);
+            prop_assert_eq!(
+                before.contains(x) || after.contains(x),
+                a.contains(x) && !b.contains(x)
+            );
+            prop_assert!(!before.overlaps(&after));
+            prop_assert_eq!(before.len() + after.len(), a.len() - a.intersection(&b).len());
+        }
+
+        #[test]
+        fn prop_adjacency(a in interval(), b in interval()) {
+            prop_assert_eq!(a.is_adjacent(&b), b.is_adjacent(&a));
+            if a.is_adjacent(&b) {
+                prop_assert!(!a.overlaps(&b));
+                prop_assert_eq!(a.union(&b).map(|x| x.len()), Some(a.len() + b.len()));
+            }
+        }
+
+        #[test]
+        fn prop_half_open(a in -20..20, b in -20..20) {
+            prop_assert_eq!(Interval::half_open(a, b), Interval::closed(a, b - 1));
+            if a < b {
+                prop_assert_eq!(Some(a..b), Interval::from(a..b).to_range());
+            } else {
+                prop_assert!(Interval::from(a..b).is_empty());
+            }
+        }
+    }
+}
@@ -1,13 +1,11 @@
-extern crate aoc_runner;
-
-#[macro_use]
-extern crate aoc_runner_derive;
-
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-
-aoc_lib! { year = 2022}
+pub mod answers;
+pub mod csv;
+pub mod diagnostic;
+pub mod error;
+pub mod input;
+pub mod interval;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod timing;
+pub mod y2022;
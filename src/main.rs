@@ -1,7 +1,782 @@
-extern crate aoc_runner;
-extern crate aoc_runner_derive;
-extern crate rs_advent_of_code_2022;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-use aoc_runner_derive::aoc_main;
+use clap::{Args, Parser, Subcommand};
+use serde::Serialize;
 
-aoc_main! { lib = rs_advent_of_code_2022 }
+use rs_advent_of_code_2022::answers::{Answers, Verdict};
+use rs_advent_of_code_2022::error::AocError;
+use rs_advent_of_code_2022::input::{self, HttpFetcher, InputProvider};
+use rs_advent_of_code_2022::registry;
+use rs_advent_of_code_2022::scaffold;
+use rs_advent_of_code_2022::solution::Day;
+use rs_advent_of_code_2022::timing::{self, measure, Phase, TimingRow};
+use rs_advent_of_code_2022::y2022::day1::{self, InventoryStats};
+use rs_advent_of_code_2022::y2022::day2::replay::{self, Interpretation};
+use rs_advent_of_code_2022::y2022::day2::tournament::{self, Strategy, TournamentConfig};
+use rs_advent_of_code_2022::y2022::day2::{self, rules::RuleSet, solver};
+use rs_advent_of_code_2022::y2022::day3::{self, alphabet::ItemAlphabet, analytics::ItemStats};
+
+#[derive(Parser)]
+#[command(about = "Advent of code in Rust")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a single day or of all days
+    Run(RunArgs),
+    /// Check the answers of every day against the recorded answers
+    Verify(VerifyArgs),
+    /// Create src/y<year>/day<N>.rs from the day template and register the new day
+    NewDay(NewDayArgs),
+    /// Download missing puzzle inputs into the input directory, using the session in AOC_SESSION
+    Fetch(FetchArgs),
+    /// Print statistics of the elf inventories of 2022 day 1
+    Inventory(InventoryArgs),
+    /// Score the strategy guide of 2022 day 2 under custom rules
+    Guide(GuideArgs),
+    /// Let strategies play rock paper scissors against each other, including the guide of 2022 day 2
+    Tournament(TournamentArgs),
+    /// Move items between the compartments of the rucksacks of 2022 day 3 so no item type is in both
+    Rebalance(RebalanceArgs),
+    /// Print statistics of the item types in the rucksacks of 2022 day 3
+    Items(ItemsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year of the day, defaults to the latest year; with --all, defaults to every year
+    #[arg(long)]
+    year: Option<u32>,
+
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Only run this part of the day
+    #[arg(long, requires = "day")]
+    part: Option<u32>,
+
+    /// Run every implemented day
+    #[arg(long)]
+    all: bool,
+
+    /// Read the puzzle input from this file instead of input/<year>/day<N>.txt
+    #[arg(long, conflicts_with_all = ["all", "stdin"])]
+    input: Option<PathBuf>,
+
+    /// Read the puzzle input from stdin
+    #[arg(long, conflicts_with = "all")]
+    stdin: bool,
+
+    /// Print one JSON object per part instead of text
+    #[arg(long)]
+    json: bool,
+
+    /// Report the durations of reading the input, parsing and each part
+    #[arg(long)]
+    time: bool,
+
+    /// Number of repetitions used for the timing report
+    #[arg(long, default_value_t = 1, requires = "time")]
+    repeat: usize,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify the days of this year
+    #[arg(long)]
+    year: Option<u32>,
+
+    /// File with the recorded answers
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Print one JSON object per part instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    /// Number of the new day
+    day: u32,
+
+    /// Year of the new day, defaults to the latest year
+    #[arg(long)]
+    year: Option<u32>,
+
+    /// Root directory of the crate
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Year of the day, defaults to the latest year; with --all, defaults to every year
+    #[arg(long)]
+    year: Option<u32>,
+
+    /// Day to fetch
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Fetch the inputs of every implemented day
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(Args)]
+struct InventoryArgs {
+    /// Read the inventories from this file instead of input/2022/day1.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Number of buckets of the histogram of calorie totals
+    #[arg(long, default_value_t = 10)]
+    buckets: usize,
+
+    /// Print the statistics as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct GuideArgs {
+    /// Read the strategy guide from this file instead of input/2022/day2.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// TOML or JSON file with the symbol tables and scores, defaults to the puzzle rules
+    #[arg(long)]
+    rules: Option<PathBuf>,
+
+    /// Compare both interpretations with the best response of every round
+    #[arg(long)]
+    optimal: bool,
+
+    /// Print every round as the given part interprets the guide
+    #[arg(long, conflicts_with = "optimal", value_parser = clap::value_parser!(u32).range(1..=2))]
+    replay: Option<u32>,
+
+    /// Print the replay as CSV instead of a table
+    #[arg(long, requires = "replay")]
+    csv: bool,
+}
+
+#[derive(Args)]
+struct TournamentArgs {
+    /// Read the strategy guide from this file instead of input/2022/day2.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Number of matches of every pair of strategies
+    #[arg(long, default_value_t = 100)]
+    matches: usize,
+
+    /// Number of rounds of every match
+    #[arg(long, default_value_t = 1000)]
+    rounds: usize,
+
+    /// Seed of the random number generator
+    #[arg(long, default_value_t = 2022)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct RebalanceArgs {
+    /// Read the rucksacks from this file instead of input/2022/day3.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Write the rebalanced rucksacks to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Ordered characters of the items, with priorities from 1, defaults to a-z followed by A-Z
+    #[arg(long)]
+    alphabet: Option<String>,
+}
+
+#[derive(Args)]
+struct ItemsArgs {
+    /// Read the rucksacks from this file instead of input/2022/day3.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Number of most duplicated item types to list
+    #[arg(long, default_value_t = 5)]
+    top: usize,
+
+    /// Number of rucksacks in an elf group
+    #[arg(long, default_value_t = day3::GROUP_SIZE)]
+    group_size: usize,
+
+    /// Print the statistics as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+
+    /// Print the priority histogram as CSV instead of a table
+    #[arg(long, conflicts_with = "json")]
+    csv: bool,
+
+    /// Ordered characters of the items, with priorities from 1, defaults to a-z followed by A-Z
+    #[arg(long)]
+    alphabet: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PartReport {
+    year: u32,
+    day: u32,
+    part: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TimingReport {
+    year: u32,
+    day: u32,
+    phase: String,
+    min_ns: u128,
+    median_ns: u128,
+    max_ns: u128,
+    samples: usize,
+}
+
+#[derive(Serialize)]
+struct VerifyReport<'a> {
+    year: u32,
+    day: u32,
+    part: u32,
+    status: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("failed to read {}: {}", path.display(), e),
+        )
+    })
+}
+
+fn input_provider() -> InputProvider<HttpFetcher> {
+    InputProvider::new(input::CACHE_DIR, HttpFetcher::from_env())
+}
+
+fn read_input(args: &RunArgs, year: u32, day: u32) -> Result<String, String> {
+    if args.stdin {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| e.to_string())?;
+        Ok(input)
+    } else {
+        match &args.input {
+            Some(path) => read_file(path).map_err(|e| e.to_string()),
+            None => input_provider().get(year, day).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+fn label(day: &dyn Day) -> String {
+    format!("{} Day {}", day.year(), day.day())
+}
+
+fn report(args: &RunArgs, day: &dyn Day, part: u32, result: Result<String, String>) {
+    if args.json {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(error) => (None, Some(error)),
+        };
+        let report = PartReport {
+            year: day.year(),
+            day: day.day(),
+            part,
+            answer,
+            error,
+        };
+        println!(
+            "{}",
+            serde_json::to_string(&report).expect("report is serializable")
+        );
+    } else {
+        match result {
+            Ok(answer) => println!("{} - Part {}: {}", label(day), part, answer),
+            Err(error) => eprintln!("{} - Part {}: FAILED\n{}\n", label(day), part, error),
+        }
+    }
+}
+
+fn solve_day(
+    args: &RunArgs,
+    day: &dyn Day,
+    parts: &[u32],
+    timings: &mut Vec<TimingRow>,
+) -> Vec<Result<String, String>> {
+    let error_message = |e: AocError| {
+        if args.json {
+            e.to_string()
+        } else {
+            format!("{:#}", e)
+        }
+    };
+    let repeat = if args.time { args.repeat } else { 1 };
+    let mut record = |phase, summary| {
+        if args.time {
+            timings.push(TimingRow {
+                year: day.year(),
+                day: day.day(),
+                phase,
+                summary,
+            })
+        }
+    };
+
+    // stdin can only be consumed once
+    let read_repeat = if args.stdin { 1 } else { repeat };
+    let input = match measure(read_repeat, || read_input(args, day.year(), day.day())) {
+        Ok((input, summary)) => {
+            record(Phase::Read, summary);
+            input
+        }
+        Err(e) => return vec![Err(e); parts.len()],
+    };
+    let parsed = match measure(repeat, || day.parse(registry::prepare_input(&input))) {
+        Ok((parsed, summary)) => {
+            record(Phase::Parse, summary);
+            parsed
+        }
+        Err(e) => return vec![Err(error_message(e)); parts.len()],
+    };
+
+    parts
+        .iter()
+        .map(|part| {
+            let (answer, summary) = measure(repeat, || {
+                parsed
+                    .part(*part)
+                    .expect("only existing parts are requested")
+            })
+            .map_err(error_message)?;
+            record(Phase::Part(*part), summary);
+            Ok(answer)
+        })
+        .collect()
+}
+
+fn report_timings(args: &RunArgs, timings: &[TimingRow]) {
+    if args.json {
+        for row in timings {
+            let report = TimingReport {
+                year: row.year,
+                day: row.day,
+                phase: row.phase.to_string(),
+                min_ns: row.summary.min.as_nanos(),
+                median_ns: row.summary.median.as_nanos(),
+                max_ns: row.summary.max.as_nanos(),
+                samples: row.summary.samples,
+            };
+            println!(
+                "{}",
+                serde_json::to_string(&report).expect("report is serializable")
+            );
+        }
+    } else {
+        print!("\n{}", timing::render_table(timings));
+    }
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let days: Vec<&dyn Day> = match args.day {
+        Some(day) => {
+            let year = args.year.unwrap_or_else(registry::latest_year);
+            match registry::find(year, day) {
+                Some(day) => vec![day],
+                None => {
+                    eprintln!("day {} of {} is not implemented", day, year);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => registry::days_of(args.year).collect(),
+    };
+    let parts: Vec<u32> = match args.part {
+        Some(part) if registry::PARTS.contains(&part) => vec![part],
+        Some(part) => {
+            eprintln!("part {} does not exist", part);
+            return ExitCode::FAILURE;
+        }
+        None => registry::PARTS.to_vec(),
+    };
+
+    let mut success = true;
+    let mut timings = vec![];
+    for day in days {
+        for (part, result) in parts.iter().zip(solve_day(args, day, &parts, &mut timings)) {
+            success &= result.is_ok();
+            report(args, day, *part, result);
+        }
+    }
+    if args.time {
+        report_timings(args, &timings);
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify_day(day: &dyn Day, answers: &Answers) -> Vec<Verdict> {
+    let input = match input_provider().get(day.year(), day.day()) {
+        Ok(input) => input,
+        Err(e) => return vec![Verdict::Error(e.to_string()); registry::PARTS.len()],
+    };
+    let parsed = match day.parse(registry::prepare_input(&input)) {
+        Ok(parsed) => parsed,
+        Err(e) => return vec![Verdict::Error(e.to_string()); registry::PARTS.len()],
+    };
+
+    registry::PARTS
+        .iter()
+        .map(|part| {
+            let actual = parsed.part(*part).expect("part exists");
+            Verdict::new(answers.get(day.year(), day.day(), *part), actual)
+        })
+        .collect()
+}
+
+fn verify(args: &VerifyArgs) -> ExitCode {
+    let answers = match read_file(&args.answers)
+        .map_err(|e| e.to_string())
+        .and_then(|x| Answers::from_toml(&x).map_err(|e| e.to_string()))
+    {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut regression = false;
+    for day in registry::days_of(args.year) {
+        for (part, verdict) in registry::PARTS.iter().zip(verify_day(day, &answers)) {
+            regression |= verdict.is_regression();
+
+            if args.json {
+                let (status, expected, actual, error) = match &verdict {
+                    Verdict::Pass => ("pass", None, None, None),
+                    Verdict::Fail { expected, actual } => {
+                        ("fail", Some(expected.as_str()), Some(actual.as_str()), None)
+                    }
+                    Verdict::Missing { actual } => ("missing", None, Some(actual.as_str()), None),
+                    Verdict::Error(error) => ("error", None, None, Some(error.as_str())),
+                };
+                let report = VerifyReport {
+                    year: day.year(),
+                    day: day.day(),
+                    part: *part,
+                    status,
+                    expected,
+                    actual,
+                    error,
+                };
+                println!(
+                    "{}",
+                    serde_json::to_string(&report).expect("report is serializable")
+                );
+            } else {
+                println!("{} - Part {}: {}", label(day), part, verdict);
+            }
+        }
+    }
+
+    if regression {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_day(args: &NewDayArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    match scaffold::new_day(&args.root, year, args.day) {
+        Ok(changed) => {
+            changed
+                .iter()
+                .for_each(|path| println!("wrote {}", path.display()));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(args: &FetchArgs) -> ExitCode {
+    let days: Vec<(u32, u32)> = match args.day {
+        Some(day) => vec![(args.year.unwrap_or_else(registry::latest_year), day)],
+        None => registry::days_of(args.year)
+            .map(|x| (x.year(), x.day()))
+            .collect(),
+    };
+
+    let provider = input_provider();
+    let mut success = true;
+    for (year, day) in days {
+        match provider.get(year, day) {
+            Ok(_) => println!("{}", provider.path(year, day).display()),
+            Err(e) => {
+                eprintln!("{} day {}: {}", year, day, e);
+                success = false;
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// reads `path` if given, otherwise the cached input of the day
+fn read_day_input(path: Option<&Path>, year: u32, day: u32) -> Result<String, String> {
+    match path {
+        Some(path) => read_file(path).map_err(|e| e.to_string()),
+        None => input_provider().get(year, day).map_err(|e| e.to_string()),
+    }
+}
+
+fn inventory(args: &InventoryArgs) -> ExitCode {
+    let elves = match read_day_input(args.input.as_deref(), 2022, 1).and_then(|x| {
+        day1::parse::<usize>(registry::prepare_input(&x)).map_err(|e| format!("{:#}", e))
+    }) {
+        Ok(elves) => elves,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match InventoryStats::new(&elves, args.buckets) {
+        Ok(stats) if args.json => println!(
+            "{}",
+            serde_json::to_string(&stats).expect("stats are serializable")
+        ),
+        Ok(stats) => print!("{}", stats.render_table()),
+        Err(e) => {
+            eprintln!("{:#}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// json files are read as JSON, everything else as TOML
+fn read_rules(path: &Path) -> Result<RuleSet, String> {
+    let content = read_file(path).map_err(|e| e.to_string())?;
+    let rules = match path.extension() {
+        Some(x) if x == "json" => RuleSet::from_json(&content),
+        _ => RuleSet::from_toml(&content),
+    };
+    rules.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn guide(args: &GuideArgs) -> ExitCode {
+    let rules = match &args.rules {
+        Some(path) => read_rules(path),
+        None => Ok(RuleSet::default()),
+    };
+    let report = rules.and_then(|rules| {
+        let input = read_day_input(args.input.as_deref(), 2022, 2)?;
+        let guide =
+            day2::parse(registry::prepare_input(&input), &rules).map_err(|e| format!("{:#}", e))?;
+        if args.optimal {
+            let analysis = solver::analyse(&guide, &rules).map_err(|e| format!("{:#}", e))?;
+            return Ok(analysis.render_table(&rules));
+        }
+        if let Some(part) = args.replay {
+            let interpretation = match part {
+                1 => Interpretation::Part1,
+                _ => Interpretation::Part2,
+            };
+            let rounds: Vec<_> = replay::replay(&guide, &rules, interpretation)
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("{:#}", e))?;
+            return Ok(if args.csv {
+                replay::to_csv(&rounds, &rules)
+            } else {
+                replay::render_table(&rounds, &rules)
+            });
+        }
+        Ok(format!(
+            "Part 1: {}\nPart 2: {}\n",
+            day2::part1(&guide, &rules).map_err(|e| format!("{:#}", e))?,
+            day2::part2(&guide, &rules).map_err(|e| format!("{:#}", e))?,
+        ))
+    });
+
+    match report {
+        Ok(report) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn tournament(args: &TournamentArgs) -> ExitCode {
+    let rules = RuleSet::default();
+    let strategy = read_day_input(args.input.as_deref(), 2022, 2).and_then(|input| {
+        day2::parse(registry::prepare_input(&input), &rules)
+            .and_then(|guide| Strategy::from_guide(&guide, &rules, Interpretation::Part1))
+            .map_err(|e| format!("{:#}", e))
+    });
+    let strategies = match strategy {
+        Ok(strategy) => [
+            strategy,
+            Strategy::Random,
+            Strategy::Frequency,
+            Strategy::Markov,
+        ],
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = TournamentConfig {
+        matches: args.matches,
+        rounds: args.rounds,
+        seed: args.seed,
+    };
+
+    match tournament::tournament(&strategies, &config) {
+        Ok(reports) => {
+            print!("{}", tournament::render_table(&reports));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn read_rucksacks(
+    path: Option<&Path>,
+    alphabet: &ItemAlphabet,
+) -> Result<Vec<day3::Rucksack>, String> {
+    let input = read_day_input(path, 2022, 3)?;
+    day3::parse_with_alphabet(registry::prepare_input(&input), alphabet)
+        .map_err(|e| format!("{:#}", e))
+}
+
+fn item_alphabet(chars: Option<&str>) -> Result<ItemAlphabet, String> {
+    match chars {
+        Some(chars) => ItemAlphabet::new(chars).map_err(|e| format!("invalid alphabet: {}", e)),
+        None => Ok(ItemAlphabet::default()),
+    }
+}
+
+fn rebalance(args: &RebalanceArgs) -> ExitCode {
+    let rucksacks = match item_alphabet(args.alphabet.as_deref())
+        .and_then(|alphabet| read_rucksacks(args.input.as_deref(), &alphabet))
+    {
+        Ok(rucksacks) => rucksacks,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut fixed = String::new();
+    let (mut moves, mut cost) = (0, 0);
+    for (idx, rucksack) in rucksacks.iter().enumerate() {
+        match rucksack.rebalance() {
+            Some(rebalanced) => {
+                fixed += &format!("{}{}\n", rebalanced.comp1, rebalanced.comp2);
+                moves += rebalanced.moves.len();
+                cost += rebalanced.cost;
+            }
+            None => {
+                eprintln!("line {}: {} cannot be rebalanced", idx + 1, rucksack);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, &fixed) {
+                eprintln!("failed to write {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", fixed),
+    }
+    eprintln!(
+        "rebalanced {} rucksacks with {} moves, priority cost {}",
+        rucksacks.len(),
+        moves,
+        cost
+    );
+    ExitCode::SUCCESS
+}
+
+fn items(args: &ItemsArgs) -> ExitCode {
+    let stats = item_alphabet(args.alphabet.as_deref()).and_then(|alphabet| {
+        let rucksacks = read_rucksacks(args.input.as_deref(), &alphabet)?;
+        ItemStats::new(&rucksacks, &alphabet, args.group_size, args.top)
+            .map_err(|e| format!("{:#}", e))
+    });
+
+    match stats {
+        Ok(stats) if args.json => println!(
+            "{}",
+            serde_json::to_string(&stats).expect("stats are serializable")
+        ),
+        Ok(stats) if args.csv => print!("{}", stats.histogram_csv()),
+        Ok(stats) => print!("{}", stats.render_table()),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Inventory(args) => inventory(args),
+        Command::Guide(args) => guide(args),
+        Command::Tournament(args) => tournament(args),
+        Command::Rebalance(args) => rebalance(args),
+        Command::Items(args) => items(args),
+    }
+}
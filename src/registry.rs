@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use crate::input;
+use crate::solution::Day;
+
+pub const PARTS: [u32; 2] = [1, 2];
+
+// ordered by year and day
+pub static DAYS: &[&dyn Day] = &[
+    &crate::y2022::day1::Day1,
+    &crate::y2022::day2::Day2,
+    &crate::y2022::day3::Day3,
+    &crate::y2022::day4::Day4,
+    &crate::y2022::day5::Day5,
+    &crate::y2022::day6::Day6,
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Day> {
+    DAYS.iter()
+        .copied()
+        .find(|x| x.year() == year && x.day() == day)
+}
+
+pub fn days_of(year: Option<u32>) -> impl Iterator<Item = &'static dyn Day> {
+    DAYS.iter()
+        .copied()
+        .filter(move |x| year.is_none_or(|year| x.year() == year))
+}
+
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<_> = DAYS.iter().map(|x| x.year()).collect();
+    years.dedup();
+    years
+}
+
+pub fn latest_year() -> u32 {
+    DAYS.last().map_or(2022, |x| x.year())
+}
+
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    input::cache_path(Path::new(input::CACHE_DIR), year, day)
+}
+
+// drops the trailing newlines of the input file, like aoc-runner did
+pub fn prepare_input(input: &str) -> &str {
+    input.trim_end_matches('\n')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        assert!(DAYS
+            .windows(2)
+            .all(|x| (x[0].year(), x[0].day()) < (x[1].year(), x[1].day())));
+        assert_eq!(Some((2022, 1)), find(2022, 1).map(|x| (x.year(), x.day())));
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 1).is_none());
+        assert!(years().contains(&2022));
+        assert_eq!(0, days_of(Some(2015)).count());
+    }
+
+    #[test]
+    fn test_solve() {
+        let parsed = find(2022, 6)
+            .unwrap()
+            .parse(prepare_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"))
+            .unwrap();
+
+        assert_eq!(Some(Ok("7".to_string())), parsed.part(1));
+        assert_eq!(Some(Ok("19".to_string())), parsed.part(2));
+        assert_eq!(None, parsed.part(3));
+    }
+}
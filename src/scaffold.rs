@@ -0,0 +1,260 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::registry;
+
+const TEMPLATE: &str = include_str!("day_template.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(PathBuf, io::Error),
+    AlreadyExists(PathBuf),
+    AlreadyRegistered(PathBuf),
+    MissingEntries(PathBuf),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::AlreadyRegistered(path) => {
+                write!(f, "day is already registered in {}", path.display())
+            }
+            ScaffoldError::MissingEntries(path) => {
+                write!(f, "found no day entries to extend in {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+pub fn render_template(year: u32, day: u32) -> String {
+    TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InsertError {
+    AlreadyRegistered,
+    MissingEntries,
+}
+
+// inserts `new_line` into the lines recognised by `key_of`, keeping them ordered by their key
+fn insert_entry<K: Ord>(
+    content: &str,
+    key: K,
+    new_line: &str,
+    key_of: impl Fn(&str) -> Option<K>,
+) -> Result<String, InsertError> {
+    let mut lines: Vec<_> = content.lines().collect();
+    let entries: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| key_of(line).map(|x| (idx, x)))
+        .collect();
+
+    if entries.iter().any(|(_, x)| *x == key) {
+        return Err(InsertError::AlreadyRegistered);
+    }
+    let (last_idx, _) = entries.last().ok_or(InsertError::MissingEntries)?;
+    let idx = entries
+        .iter()
+        .find(|(_, x)| *x > key)
+        .map_or(last_idx + 1, |(idx, _)| *idx);
+
+    lines.insert(idx, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_module(mod_rs: &str, prefix: &str, number: u32) -> Result<String, InsertError> {
+    let new_line = format!("pub mod {}{};", prefix, number);
+    insert_entry(mod_rs, number, &new_line, |line| {
+        line.strip_prefix("pub mod ")?
+            .strip_prefix(prefix)?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })
+}
+
+fn register_solution(registry_rs: &str, year: u32, day: u32) -> Result<String, InsertError> {
+    let new_line = format!("    &crate::y{0}::day{1}::Day{1},", year, day);
+    insert_entry(registry_rs, (year, day), &new_line, |line| {
+        let (year, rest) = line.trim().strip_prefix("&crate::y")?.split_once("::day")?;
+        let (day, _) = rest.split_once("::")?;
+        Some((year.parse().ok()?, day.parse().ok()?))
+    })
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ScaffoldError::Io(dir.to_path_buf(), e))?;
+    }
+    fs::write(path, content).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+}
+
+// creates src/y<year>/day<N>.rs and an empty input file below `root` and registers the new
+// day, including the module of the year if it is the first day of that year,
+// returns the created or modified files
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let source = root.join(format!("src/y{}/day{}.rs", year, day));
+    let year_rs = root.join(format!("src/y{}/mod.rs", year));
+    let input = root.join(registry::input_path(year, day));
+    let lib_rs = root.join("src/lib.rs");
+    let registry_rs = root.join("src/registry.rs");
+
+    if source.exists() {
+        return Err(ScaffoldError::AlreadyExists(source));
+    }
+
+    let to_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |e| match e {
+            InsertError::AlreadyRegistered => ScaffoldError::AlreadyRegistered(path),
+            InsertError::MissingEntries => ScaffoldError::MissingEntries(path),
+        }
+    };
+
+    let mut changes = vec![];
+    if year_rs.exists() {
+        let new_year_rs =
+            register_module(&read(&year_rs)?, "day", day).map_err(to_error(&year_rs))?;
+        changes.push((year_rs, new_year_rs));
+    } else {
+        let new_lib_rs = register_module(&read(&lib_rs)?, "y", year).map_err(to_error(&lib_rs))?;
+        changes.push((lib_rs, new_lib_rs));
+        changes.push((year_rs, format!("pub mod day{};\n", day)));
+    }
+    let new_registry_rs =
+        register_solution(&read(&registry_rs)?, year, day).map_err(to_error(&registry_rs))?;
+    changes.push((registry_rs, new_registry_rs));
+    changes.push((source, render_template(year, day)));
+    if !input.exists() {
+        changes.push((input, String::new()));
+    }
+
+    for (path, content) in &changes {
+        write(path, content)?;
+    }
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let rendered = render_template(2023, 7);
+
+        assert!(rendered.contains("pub struct Day7;"));
+        assert!(rendered.contains("const YEAR: u32 = 2023;"));
+        assert!(rendered.contains("const DAY: u32 = 7;"));
+        assert!(!rendered.contains("__DAY__"));
+        assert!(!rendered.contains("__YEAR__"));
+    }
+
+    #[test]
+    fn test_register_module() {
+        let mod_rs = "pub mod day1;\npub mod day3;\npub mod error;\n";
+
+        assert_eq!(
+            Ok("pub mod day1;\npub mod day2;\npub mod day3;\npub mod error;\n".to_string()),
+            register_module(mod_rs, "day", 2)
+        );
+        assert_eq!(
+            Ok("pub mod day1;\npub mod day3;\npub mod day10;\npub mod error;\n".to_string()),
+            register_module(mod_rs, "day", 10)
+        );
+        assert_eq!(
+            Err(InsertError::AlreadyRegistered),
+            register_module(mod_rs, "day", 3)
+        );
+        assert_eq!(
+            Err(InsertError::MissingEntries),
+            register_module("pub mod error;\n", "day", 1)
+        );
+        assert_eq!(
+            Ok("pub mod error;\npub mod y2022;\npub mod y2023;\n".to_string()),
+            register_module("pub mod error;\npub mod y2022;\n", "y", 2023)
+        );
+    }
+
+    #[test]
+    fn test_register_solution() {
+        let registry_rs = "\
+pub static DAYS: &[&dyn Day] = &[
+    &crate::y2022::day1::Day1,
+    &crate::y2023::day1::Day1,
+];
+";
+
+        assert_eq!(
+            Ok("\
+pub static DAYS: &[&dyn Day] = &[
+    &crate::y2022::day1::Day1,
+    &crate::y2022::day2::Day2,
+    &crate::y2023::day1::Day1,
+];
+"
+            .to_string()),
+            register_solution(registry_rs, 2022, 2)
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2022")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod y2022;\n").unwrap();
+        fs::write(root.join("src/y2022/mod.rs"), "pub mod day1;\n").unwrap();
+        fs::write(
+            root.join("src/registry.rs"),
+            "pub static DAYS: &[&dyn Day] = &[\n    &crate::y2022::day1::Day1,\n];\n",
+        )
+        .unwrap();
+
+        let changed = new_day(&root, 2022, 2).unwrap();
+
+        assert_eq!(4, changed.len());
+        assert_eq!(
+            render_template(2022, 2),
+            read(&root.join("src/y2022/day2.rs")).unwrap()
+        );
+        assert_eq!("", read(&root.join("input/2022/day2.txt")).unwrap());
+        assert_eq!(
+            "pub mod day1;\npub mod day2;\n",
+            read(&root.join("src/y2022/mod.rs")).unwrap()
+        );
+        assert!(matches!(
+            new_day(&root, 2022, 2),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+
+        let changed = new_day(&root, 2023, 1).unwrap();
+
+        assert_eq!(5, changed.len());
+        assert_eq!(
+            "pub mod y2022;\npub mod y2023;\n",
+            read(&root.join("src/lib.rs")).unwrap()
+        );
+        assert_eq!(
+            "pub mod day1;\n",
+            read(&root.join("src/y2023/mod.rs")).unwrap()
+        );
+        assert!(read(&root.join("src/registry.rs"))
+            .unwrap()
+            .contains("    &crate::y2023::day1::Day1,\n];"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use crate::error::AocError;
+
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+    type Input: 'static;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError>;
+}
+
+// object safe counterpart of `Solution`, implemented for every solution so days can be
+// stored in the registry and run without knowing their input type
+pub trait Day: Sync {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn parse(&self, input: &str) -> Result<Box<dyn ParsedInput>, AocError>;
+}
+
+pub trait ParsedInput {
+    fn part1(&self) -> Result<String, AocError>;
+    fn part2(&self) -> Result<String, AocError>;
+
+    fn part(&self, part: u32) -> Option<Result<String, AocError>> {
+        match part {
+            1 => Some(self.part1()),
+            2 => Some(self.part2()),
+            _ => None,
+        }
+    }
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> ParsedInput for Parsed<S> {
+    fn part1(&self) -> Result<String, AocError> {
+        S::part1(&self.0).map(|x| x.to_string())
+    }
+
+    fn part2(&self) -> Result<String, AocError> {
+        S::part2(&self.0).map(|x| x.to_string())
+    }
+}
+
+impl<S: Solution + Sync + 'static> Day for S {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn ParsedInput>, AocError> {
+        Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+    }
+}
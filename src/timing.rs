@@ -0,0 +1,163 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Phase {
+    Read,
+    Parse,
+    Part(u32),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Read => write!(f, "read"),
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub samples: usize,
+}
+
+impl Summary {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (*samples.get(mid.checked_sub(1)?)? + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Some(Summary {
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+            samples: samples.len(),
+        })
+    }
+}
+
+// runs `f` `repeat` times (at least once) and returns the last result with the timing summary,
+// stops at the first error
+pub fn measure<T, E>(
+    repeat: usize,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<(T, Summary), E> {
+    let mut samples = Vec::with_capacity(repeat.max(1));
+    let mut result = None;
+    for _ in 0..repeat.max(1) {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    let summary = Summary::new(samples).expect("at least one sample");
+    Ok((result.expect("at least one run"), summary))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimingRow {
+    pub year: u32,
+    pub day: u32,
+    pub phase: Phase,
+    pub summary: Summary,
+}
+
+pub fn render_table(rows: &[TimingRow]) -> String {
+    let mut table = format!(
+        "{:>4} | {:>3} | {:<7} | {:>12} | {:>12} | {:>12} | {:>7}\n",
+        "year", "day", "phase", "min", "median", "max", "samples"
+    );
+    table.push_str(&format!(
+        "{:-<5}+{:-<5}+{:-<9}+{:-<14}+{:-<14}+{:-<14}+{:-<8}\n",
+        "", "", "", "", "", "", ""
+    ));
+    for row in rows {
+        table.push_str(&format!(
+            "{:>4} | {:>3} | {:<7} | {:>12} | {:>12} | {:>12} | {:>7}\n",
+            row.year,
+            row.day,
+            row.phase.to_string(),
+            format!("{:.1?}", row.summary.min),
+            format!("{:.1?}", row.summary.median),
+            format!("{:.1?}", row.summary.max),
+            row.summary.samples
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Some(Summary {
+                min: ms(1),
+                median: ms(3),
+                max: ms(7),
+                samples: 3
+            }),
+            Summary::new(vec![ms(7), ms(1), ms(3)])
+        );
+        assert_eq!(
+            ms(4),
+            Summary::new(vec![ms(7), ms(1), ms(3), ms(5)])
+                .unwrap()
+                .median
+        );
+        assert_eq!(None, Summary::new(vec![]));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let (value, summary) = measure(5, || {
+            calls += 1;
+            Ok::<_, ()>(calls)
+        })
+        .unwrap();
+
+        assert_eq!(5, value);
+        assert_eq!(5, summary.samples);
+        assert_eq!(Err("failed"), measure(5, || Err::<(), _>("failed")));
+    }
+
+    #[test]
+    fn test_render_table() {
+        let summary = Summary {
+            min: Duration::from_micros(12),
+            median: Duration::from_micros(15),
+            max: Duration::from_micros(40),
+            samples: 3,
+        };
+        let table = render_table(&[TimingRow {
+            year: 2022,
+            day: 3,
+            phase: Phase::Part(2),
+            summary,
+        }]);
+
+        assert_eq!(
+            vec![
+                "year | day | phase   |          min |       median |          max | samples",
+                "-----+-----+---------+--------------+--------------+--------------+--------",
+                "2022 |   3 | part 2  |       12.0µs |       15.0µs |       40.0µs |       3",
+            ],
+            table.lines().collect::<Vec<_>>()
+        );
+    }
+}
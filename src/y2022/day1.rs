@@ -0,0 +1,789 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{AocError, LocatedError};
+use crate::solution::Solution;
+
+// `elf` is the position of the elf in the input, starting at 0
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    InvalidCalories { elf: usize, source: ParseIntError },
+    EmptyGroup { elf: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCalories { elf, source } => {
+                write!(f, "invalid calorie count of elf {}: {}", elf, source)
+            }
+            ParseError::EmptyGroup { elf } => write!(f, "elf {} carries no items", elf),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// the total of a single elf (`elf` is its position, starting at 0) or of the top elves
+// does not fit into the calorie type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OverflowError {
+    pub elf: Option<usize>,
+    pub calorie_type: &'static str,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.elf {
+            Some(elf) => write!(
+                f,
+                "calorie total of elf {} overflows {}",
+                elf, self.calorie_type
+            ),
+            None => write!(
+                f,
+                "sum of the top calorie totals overflows {}",
+                self.calorie_type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+// integer type used for calorie counts, totals are always added with overflow checks, the
+// statistics compute with the values widened to `u128`
+pub trait Calories: Copy + Ord + fmt::Debug + fmt::Display + FromStr<Err = ParseIntError> {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn to_u128(self) -> u128;
+    // `value` has to be in the range of the type
+    fn from_u128(value: u128) -> Self;
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(
+            impl Calories for $t {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+
+                fn from_u128(value: u128) -> Self {
+                    <$t>::try_from(value).expect("value in the range of the calorie type")
+                }
+            }
+        )*
+    };
+}
+
+impl_calories!(u32, u64, u128, usize);
+
+fn overflow<C>(elf: Option<usize>) -> AocError {
+    AocError::Overflow(OverflowError {
+        elf,
+        calorie_type: std::any::type_name::<C>(),
+    })
+}
+
+fn checked_sum<C: Calories>(calories: impl IntoIterator<Item = C>) -> Option<C> {
+    calories
+        .into_iter()
+        .try_fold(C::ZERO, |sum, x| sum.checked_add(x))
+}
+
+// how blank lines between elves are treated
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseMode {
+    // any run of blank lines separates two elves, blank lines at the start are skipped
+    Tolerant,
+    // exactly one blank line separates two elves, anything else is an elf without items
+    Strict,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Elf<C = usize> {
+    calories: Vec<C>,
+}
+
+impl<C: Calories> Elf<C> {
+    fn new<I>(calories: I) -> Elf<C>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Elf {
+            calories: Vec::from_iter(calories),
+        }
+    }
+
+    // `None` if the total does not fit into `C`
+    pub fn total(&self) -> Option<C> {
+        checked_sum(self.calories.iter().copied())
+    }
+}
+
+// the totals of `elves` in order, fails at the first total that overflows
+pub fn totals<C: Calories>(elves: &[Elf<C>]) -> impl Iterator<Item = Result<C, AocError>> + '_ {
+    elves
+        .iter()
+        .enumerate()
+        .map(|(idx, elf)| elf.total().ok_or_else(|| overflow::<C>(Some(idx))))
+}
+
+// parses one elf at a time from a reader, so only the current elf is kept in memory,
+// lines may end with `\n` or `\r\n` and lines of only whitespace count as blank
+pub struct Elves<R, C = usize> {
+    reader: R,
+    mode: ParseMode,
+    line: String,
+    line_number: usize,
+    offset: usize,
+    elves: usize,
+    done: bool,
+    calories: PhantomData<C>,
+}
+
+impl<R: BufRead, C: Calories> Elves<R, C> {
+    pub fn new(reader: R) -> Self {
+        Elves::with_mode(reader, ParseMode::Tolerant)
+    }
+
+    pub fn with_mode(reader: R, mode: ParseMode) -> Self {
+        Elves {
+            reader,
+            mode,
+            line: String::new(),
+            line_number: 0,
+            offset: 0,
+            elves: 0,
+            done: false,
+            calories: PhantomData,
+        }
+    }
+
+    // reads the next line into `self.line` and returns its position, `None` at the end of input
+    fn next_line(&mut self) -> Result<Option<(usize, usize)>, AocError> {
+        self.line.clear();
+        let read = self
+            .reader
+            .read_line(&mut self.line)
+            .map_err(|e| AocError::Io(e.to_string()))?;
+        if read == 0 {
+            return Ok(None);
+        }
+
+        let position = (self.line_number, self.offset);
+        self.line_number += 1;
+        self.offset += read;
+        Ok(Some(position))
+    }
+
+    fn next_elf(&mut self) -> Result<Option<Elf<C>>, AocError> {
+        let elf = self.elves;
+        let mut calories = vec![];
+        while let Some((line_number, offset)) = self.next_line()? {
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            let item = line.trim();
+            let diagnostic =
+                || Diagnostic::new(line, item, "a calorie count").shifted(line_number, offset);
+
+            if item.is_empty() {
+                if !calories.is_empty() {
+                    self.elves += 1;
+                    return Ok(Some(Elf::new(calories)));
+                }
+                if self.mode == ParseMode::Strict {
+                    return Err(ParseError::EmptyGroup { elf }.at(diagnostic()));
+                }
+                continue;
+            }
+
+            let calorie = item
+                .parse()
+                .map_err(|source| ParseError::InvalidCalories { elf, source }.at(diagnostic()))?;
+            calories.push(calorie);
+        }
+
+        self.done = true;
+        self.elves += 1;
+        Ok((!calories.is_empty()).then(|| Elf::new(calories)))
+    }
+}
+
+impl<R: BufRead, C: Calories> Iterator for Elves<R, C> {
+    type Item = Result<Elf<C>, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let elf = self.next_elf();
+        if elf.is_err() {
+            self.done = true;
+        }
+        elf.transpose()
+    }
+}
+
+pub fn parse<C: Calories>(input: &str) -> Result<Vec<Elf<C>>, AocError> {
+    parse_with_mode(input, ParseMode::Tolerant)
+}
+
+pub fn parse_with_mode<C: Calories>(input: &str, mode: ParseMode) -> Result<Vec<Elf<C>>, AocError> {
+    Elves::with_mode(input.as_bytes(), mode).collect()
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RankedElf<C = usize> {
+    // position of the elf in the input, starting at 0
+    pub elf: usize,
+    pub calories: C,
+}
+
+// keeps the `k` largest totals in a min-heap, returns them in descending order,
+// on ties the elf that comes first wins
+fn top_k<C, I>(totals: I, k: usize) -> Result<Vec<RankedElf<C>>, AocError>
+where
+    C: Calories,
+    I: IntoIterator<Item = Result<C, AocError>>,
+{
+    // `k` can be far more than the number of elves, the heap grows as needed beyond that
+    let mut heap = BinaryHeap::with_capacity(k.saturating_add(1).min(1024));
+    for (elf, calories) in totals.into_iter().enumerate() {
+        heap.push(Reverse((calories?, Reverse(elf))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(elf)))| RankedElf { elf, calories })
+        .collect())
+}
+
+// streams the elves from `input` and keeps only the `k` best of them in memory
+pub fn top_k_calories<C: Calories, R: BufRead>(
+    input: R,
+    k: usize,
+) -> Result<Vec<RankedElf<C>>, AocError> {
+    let totals = Elves::<R, C>::new(input)
+        .enumerate()
+        .map(|(idx, elf)| elf?.total().ok_or_else(|| overflow::<C>(Some(idx))));
+    top_k(totals, k)
+}
+
+pub const PERCENTILES: [u32; 5] = [25, 50, 75, 90, 99];
+
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct InventoryStats<C = usize> {
+    pub elves: usize,
+    pub items: usize,
+    // per elf, in input order
+    pub totals: Vec<C>,
+    pub item_counts: Vec<usize>,
+    pub min: C,
+    pub max: C,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile<C>>,
+    pub histogram: Vec<Bucket<C>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+pub struct Percentile<C = usize> {
+    pub percentile: u32,
+    pub calories: C,
+}
+
+// counts the elves whose total lies in `start..=end`
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+pub struct Bucket<C = usize> {
+    pub start: C,
+    pub end: C,
+    pub elves: usize,
+}
+
+impl<C: Calories> InventoryStats<C> {
+    // splits the range of totals into `buckets` buckets of equal width for the histogram, fails
+    // on the first total that overflows `C`
+    pub fn new(elves: &[Elf<C>], buckets: usize) -> Result<Self, AocError> {
+        let totals = totals(elves).collect::<Result<Vec<_>, _>>()?;
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+
+        let (min, max) = match (sorted.first(), sorted.last()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => return Err(AocError::NoSolution("there are no elves")),
+        };
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1].to_u128() as f64 + sorted[mid].to_u128() as f64) / 2.0
+        } else {
+            sorted[mid].to_u128() as f64
+        };
+        let item_counts: Vec<_> = elves.iter().map(|x| x.calories.len()).collect();
+
+        Ok(InventoryStats {
+            elves: elves.len(),
+            items: item_counts.iter().sum(),
+            mean: totals.iter().map(|x| x.to_u128() as f64).sum::<f64>() / totals.len() as f64,
+            median,
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&percentile| Percentile {
+                    percentile,
+                    calories: nearest_rank(&sorted, percentile),
+                })
+                .collect(),
+            histogram: histogram(&sorted, buckets.max(1)),
+            min,
+            max,
+            totals,
+            item_counts,
+        })
+    }
+
+    pub fn render_table(&self) -> String {
+        let mut table = format!(
+            "elves: {}\nitems: {}\nmin: {}\nmax: {}\nmean: {:.1}\nmedian: {:.1}\n",
+            self.elves, self.items, self.min, self.max, self.mean, self.median
+        );
+        for x in &self.percentiles {
+            table.push_str(&format!("p{}: {}\n", x.percentile, x.calories));
+        }
+
+        let width = self
+            .histogram
+            .last()
+            .map_or(0, |x| x.end.to_string().len())
+            .max("from".len());
+        table.push_str(&format!(
+            "\n{:>w$} | {:>w$} | {:>5}\n",
+            "from",
+            "to",
+            "elves",
+            w = width
+        ));
+        for x in &self.histogram {
+            table.push_str(&format!(
+                "{:>w$} | {:>w$} | {:>5}\n",
+                x.start,
+                x.end,
+                x.elves,
+                w = width
+            ));
+        }
+        table
+    }
+}
+
+// the smallest total with at least `percentile` percent of the totals less or equal to it
+fn nearest_rank<C: Calories>(sorted: &[C], percentile: u32) -> C {
+    let rank = (percentile as usize * sorted.len()).div_ceil(100);
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn histogram<C: Calories>(sorted: &[C], buckets: usize) -> Vec<Bucket<C>> {
+    let (min, max) = (sorted[0].to_u128(), sorted[sorted.len() - 1].to_u128());
+    let width = match (max - min).checked_add(1) {
+        Some(span) => span.div_ceil(buckets as u128),
+        // the totals span every u128, a single bucket is too wide and gets clamped below
+        None => (u128::MAX / buckets as u128).saturating_add(1),
+    };
+
+    (0..buckets)
+        .map_while(|idx| Some((idx, min.checked_add((idx as u128).checked_mul(width)?)?)))
+        .take_while(|(_, start)| *start <= max)
+        .map(|(idx, start)| {
+            let end = if idx + 1 == buckets {
+                max
+            } else {
+                start.saturating_add(width - 1).min(max)
+            };
+            let (start, end) = (C::from_u128(start), C::from_u128(end));
+            Bucket {
+                start,
+                end,
+                elves: sorted.iter().filter(|x| (start..=end).contains(x)).count(),
+            }
+        })
+        .collect()
+}
+
+pub fn part1<C: Calories>(input: &[Elf<C>]) -> Result<C, AocError> {
+    totals(input)
+        .try_fold(None, |max: Option<C>, total| Ok(max.max(Some(total?))))?
+        .ok_or(AocError::NoSolution("there are no elves"))
+}
+
+pub fn part2<C: Calories>(input: &[Elf<C>]) -> Result<C, AocError> {
+    let top = top_k(totals(input), 3)?;
+    checked_sum(top.iter().map(|x| x.calories)).ok_or_else(|| overflow::<C>(None))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 1;
+    type Input = Vec<Elf>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![
+                Elf::<usize>::new(vec![1000, 2000, 3000]),
+                Elf::new(vec![4000]),
+                Elf::new(vec![5000, 6000]),
+                Elf::new(vec![7000, 8000, 9000]),
+                Elf::new(vec![10000])
+            ],
+            parse(TEST_INPUT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "1000\n2000\n\n40o0";
+        let err = parse::<usize>(input).unwrap_err();
+
+        assert_eq!(11..15, err.diagnostic().unwrap().span);
+        assert_eq!(
+            "\
+error: invalid calorie count of elf 1: invalid digit found in string
+  --> line 4, column 1
+  |
+4 | 40o0
+  | ^^^^ expected a calorie count, found `40o0`",
+            format!("{:#}", err)
+        );
+    }
+
+    #[test]
+    fn test_parse_error_in_stream() {
+        let input = "1000\r\n\r\n2000\r\n3x00\r\n";
+        let err = Elves::<_, usize>::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert_eq!(14..18, err.diagnostic().unwrap().span);
+        assert_eq!(4, err.diagnostic().unwrap().line);
+        assert_eq!("3x00", err.diagnostic().unwrap().source_line);
+    }
+
+    #[test]
+    fn test_parse_blank_lines() {
+        let expected = parse::<usize>(TEST_INPUT).unwrap();
+
+        assert_eq!(expected, parse(&TEST_INPUT.replace('\n', "\r\n")).unwrap());
+        assert_eq!(
+            expected,
+            parse(&format!(
+                "\n \n{}\n\n",
+                TEST_INPUT.replace("\n\n", "\n  \n\t\n\n")
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        assert_eq!(
+            parse::<usize>(TEST_INPUT).unwrap(),
+            parse_with_mode(&TEST_INPUT.replace('\n', "\r\n"), ParseMode::Strict).unwrap()
+        );
+
+        let input = "1000\n\n2000\n\n\n3000";
+        let err = parse_with_mode::<usize>(input, ParseMode::Strict).unwrap_err();
+        assert_eq!(
+            AocError::Calories(
+                Diagnostic::new(input, &input[12..12], "a calorie count"),
+                ParseError::EmptyGroup { elf: 2 }
+            ),
+            err
+        );
+        assert_eq!(3, parse::<usize>(input).unwrap().len());
+
+        assert!(matches!(
+            parse_with_mode::<usize>("\n1000", ParseMode::Strict),
+            Err(AocError::Calories(_, ParseError::EmptyGroup { elf: 0 }))
+        ));
+    }
+
+    #[test]
+    fn test_top_k_calories() {
+        assert_eq!(
+            vec![
+                RankedElf {
+                    elf: 3,
+                    calories: 24000
+                },
+                RankedElf {
+                    elf: 2,
+                    calories: 11000
+                },
+            ],
+            top_k_calories::<usize, _>(TEST_INPUT.as_bytes(), 2).unwrap()
+        );
+        assert_eq!(
+            5,
+            top_k_calories::<u32, _>(TEST_INPUT.as_bytes(), 10)
+                .unwrap()
+                .len()
+        );
+        assert!(top_k_calories::<u64, _>(TEST_INPUT.as_bytes(), 0)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            vec![24000, 11000, 10000, 6000, 4000],
+            top_k_calories::<usize, _>(TEST_INPUT.as_bytes(), usize::MAX)
+                .unwrap()
+                .iter()
+                .map(|x| x.calories)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_top_k_calories_ties() {
+        let top = top_k_calories::<usize, _>("5\n\n7\n\n5\n\n5".as_bytes(), 2).unwrap();
+
+        assert_eq!(
+            vec![(1, 7), (0, 5)],
+            top.iter().map(|x| (x.elf, x.calories)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_inventory_stats() {
+        let stats = InventoryStats::new(&parse::<usize>(TEST_INPUT).unwrap(), 2).unwrap();
+
+        assert_eq!(5, stats.elves);
+        assert_eq!(10, stats.items);
+        assert_eq!(vec![6000, 4000, 11000, 24000, 10000], stats.totals);
+        assert_eq!(vec![3, 1, 2, 3, 1], stats.item_counts);
+        assert_eq!((4000, 24000), (stats.min, stats.max));
+        assert_eq!(11000.0, stats.mean);
+        assert_eq!(10000.0, stats.median);
+        assert_eq!(
+            vec![6000, 10000, 11000, 24000, 24000],
+            stats
+                .percentiles
+                .iter()
+                .map(|x| x.calories)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                Bucket {
+                    start: 4000,
+                    end: 14000,
+                    elves: 4
+                },
+                Bucket {
+                    start: 14001,
+                    end: 24000,
+                    elves: 1
+                }
+            ],
+            stats.histogram
+        );
+
+        let elves = parse(&format!("0\n\n{}", usize::MAX)).unwrap();
+        assert_eq!(
+            vec![Bucket {
+                start: 0,
+                end: usize::MAX,
+                elves: 2
+            }],
+            InventoryStats::new(&elves, 1).unwrap().histogram
+        );
+        assert_eq!(
+            vec![(0, usize::MAX / 2), (usize::MAX / 2 + 1, usize::MAX)],
+            InventoryStats::new(&elves, 2)
+                .unwrap()
+                .histogram
+                .iter()
+                .map(|x| (x.start, x.end))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Err(AocError::NoSolution("there are no elves")),
+            InventoryStats::<usize>::new(&[], 2)
+        );
+    }
+
+    #[test]
+    fn test_inventory_stats_table() {
+        let stats = InventoryStats::new(&parse::<usize>("1\n2\n\n4").unwrap(), 1).unwrap();
+
+        assert_eq!(
+            vec![
+                "elves: 2",
+                "items: 3",
+                "min: 3",
+                "max: 4",
+                "mean: 3.5",
+                "median: 3.5",
+                "p25: 3",
+                "p50: 3",
+                "p75: 4",
+                "p90: 4",
+                "p99: 4",
+                "",
+                "from |   to | elves",
+                "   3 |    4 |     2",
+            ],
+            stats.render_table().lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_inventory_stats_wide_calories() {
+        let max = u64::MAX as u128;
+        let elves = parse::<u128>(&format!("{}\n{}\n\n1", max, max)).unwrap();
+        let stats = InventoryStats::new(&elves, 2).unwrap();
+
+        assert_eq!(vec![2 * max, 1], stats.totals);
+        assert_eq!((1, 2 * max), (stats.min, stats.max));
+        assert_eq!(
+            vec![(1, max), (max + 1, 2 * max)],
+            stats
+                .histogram
+                .iter()
+                .map(|x| (x.start, x.end))
+                .collect::<Vec<_>>()
+        );
+
+        let elves = parse::<u32>(&format!("1\n\n{}\n1", u32::MAX)).unwrap();
+        assert_eq!(
+            Err(AocError::Overflow(OverflowError {
+                elf: Some(1),
+                calorie_type: "u32"
+            })),
+            InventoryStats::new(&elves, 2)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = u32::MAX;
+        let elves = parse::<u32>(&format!("{}\n\n{}\n0", max, max - 1)).unwrap();
+        assert_eq!(Ok(max), part1(&elves));
+
+        let elves = parse::<u32>(&format!("1\n\n{}\n1", max)).unwrap();
+        assert_eq!(
+            Err(AocError::Overflow(OverflowError {
+                elf: Some(1),
+                calorie_type: "u32"
+            })),
+            part1(&elves)
+        );
+        assert_eq!(
+            "calorie total of elf 1 overflows u32",
+            part2(&elves).unwrap_err().to_string()
+        );
+
+        let half = max / 2;
+        let elves = parse::<u32>(&format!("{}\n\n{}\n\n1", half, half)).unwrap();
+        assert_eq!(Ok(max), part2(&elves));
+
+        let elves = parse::<u32>(&format!("{}\n\n{}\n\n2", half, half)).unwrap();
+        assert_eq!(
+            Err(AocError::Overflow(OverflowError {
+                elf: None,
+                calorie_type: "u32"
+            })),
+            part2(&elves)
+        );
+
+        let elves = parse::<u64>(&format!("{}\n\n{}", max, max)).unwrap();
+        assert_eq!(Ok(u64::from(max) * 2), part2(&elves));
+        assert!(matches!(
+            top_k_calories::<u32, _>(format!("{}\n1", max).as_bytes(), 1),
+            Err(AocError::Overflow(OverflowError { elf: Some(0), .. }))
+        ));
+        assert!(matches!(
+            parse::<u32>(&format!("{}", u64::from(max) + 1)),
+            Err(AocError::Calories(
+                _,
+                ParseError::InvalidCalories { elf: 0, .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_overflow_u128() {
+        let max = u128::MAX;
+        let elves = parse::<u128>(&format!("{}\n\n{}\n\n1", max - 1, max / 2)).unwrap();
+
+        assert_eq!(Ok(max - 1), part1(&elves));
+        assert!(matches!(
+            part2(&elves),
+            Err(AocError::Overflow(OverflowError {
+                elf: None,
+                calorie_type: "u128"
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(24000, part1(&parse::<usize>(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(45000, part2(&parse::<usize>(TEST_INPUT).unwrap()).unwrap());
+    }
+}
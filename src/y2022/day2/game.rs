@@ -0,0 +1,289 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::RockPaperScissorsResult;
+
+#[derive(Debug)]
+pub enum GameError {
+    Toml(toml::de::Error),
+    TooFewMoves(usize),
+    EvenNumberOfMoves(usize),
+    DuplicateMove(String),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::Toml(err) => write!(f, "{}", err),
+            GameError::TooFewMoves(moves) => {
+                write!(f, "a game needs at least three moves, got {}", moves)
+            }
+            GameError::EvenNumberOfMoves(moves) => write!(
+                f,
+                "a cyclic game needs an odd number of moves, got {}",
+                moves
+            ),
+            GameError::DuplicateMove(name) => write!(f, "move `{}` is defined twice", name),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<toml::de::Error> for GameError {
+    fn from(err: toml::de::Error) -> Self {
+        GameError::Toml(err)
+    }
+}
+
+// index of a move in the cyclic order of its game
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Move(usize);
+
+impl Move {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Deserialize)]
+struct GameConfig {
+    moves: Vec<String>,
+}
+
+// a game of an odd number `n` of moves in cyclic order, where move `i` beats move `j` if
+// `(i - j) mod n` is in `1..=n/2`, so every move beats as many moves as it loses against
+//
+// games can be read from files like
+//
+// moves = ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Game {
+    moves: Vec<String>,
+}
+
+impl Game {
+    pub fn new<I>(moves: I) -> Result<Game, GameError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let moves: Vec<String> = moves.into_iter().map(Into::into).collect();
+        if moves.len() < 3 {
+            return Err(GameError::TooFewMoves(moves.len()));
+        }
+        if moves.len().is_multiple_of(2) {
+            return Err(GameError::EvenNumberOfMoves(moves.len()));
+        }
+        if let Some((idx, _)) = moves
+            .iter()
+            .enumerate()
+            .find(|(idx, x)| moves[..*idx].contains(x))
+        {
+            return Err(GameError::DuplicateMove(moves[idx].clone()));
+        }
+        Ok(Game { moves })
+    }
+
+    pub fn from_toml(input: &str) -> Result<Game, GameError> {
+        let config: GameConfig = toml::from_str(input)?;
+        Game::new(config.moves)
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        Game::new(["Rock", "Paper", "Scissors"]).expect("valid game")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::new(["Rock", "Spock", "Paper", "Lizard", "Scissors"]).expect("valid game")
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.len()).map(Move)
+    }
+
+    pub fn get(&self, index: usize) -> Option<Move> {
+        (index < self.len()).then_some(Move(index))
+    }
+
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.moves.iter().position(|x| x == name).map(Move)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.moves[m.0]
+    }
+
+    // moves in the game are scored by their position, starting at 1
+    pub fn shape_score(&self, m: Move) -> usize {
+        m.0 + 1
+    }
+
+    // shape score of the player's move plus the score of the outcome of the round
+    pub fn score(&self, player: Move, opponent: Move) -> usize {
+        self.shape_score(player) + self.outcome(player, opponent).score()
+    }
+
+    // how far `m` is ahead of `other` in the cyclic order
+    fn distance(&self, m: Move, other: Move) -> usize {
+        (m.0 + self.len() - other.0) % self.len()
+    }
+
+    pub fn outcome(&self, player: Move, opponent: Move) -> RockPaperScissorsResult {
+        match self.distance(player, opponent) {
+            0 => RockPaperScissorsResult::Draw,
+            x if x <= self.len() / 2 => RockPaperScissorsResult::Win,
+            _ => RockPaperScissorsResult::Lost,
+        }
+    }
+
+    // every move that gives `result` against `opponent`, ordered by their distance to it
+    pub fn responses(
+        &self,
+        opponent: Move,
+        result: &RockPaperScissorsResult,
+    ) -> impl Iterator<Item = Move> + '_ {
+        let half = self.len() / 2;
+        let distances = match result {
+            RockPaperScissorsResult::Draw => 0..1,
+            RockPaperScissorsResult::Win => 1..half + 1,
+            RockPaperScissorsResult::Lost => half + 1..self.len(),
+        };
+        let distances: Vec<_> = match result {
+            RockPaperScissorsResult::Lost => distances.rev().collect(),
+            _ => distances.collect(),
+        };
+        distances
+            .into_iter()
+            .map(move |x| Move((opponent.0 + x) % self.len()))
+    }
+
+    // the closest move that gives `result` against `opponent`
+    pub fn response(&self, opponent: Move, result: &RockPaperScissorsResult) -> Move {
+        self.responses(opponent, result)
+            .next()
+            .expect("every result is reachable in a game of at least three moves")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+        let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"].map(|x| game.find(x).unwrap());
+
+        assert_eq!(RockPaperScissorsResult::Win, game.outcome(paper, rock));
+        assert_eq!(RockPaperScissorsResult::Win, game.outcome(rock, scissors));
+        assert_eq!(RockPaperScissorsResult::Lost, game.outcome(scissors, rock));
+        assert_eq!(RockPaperScissorsResult::Draw, game.outcome(paper, paper));
+        assert_eq!(rock, game.response(scissors, &RockPaperScissorsResult::Win));
+        assert_eq!(
+            paper,
+            game.response(scissors, &RockPaperScissorsResult::Lost)
+        );
+        assert_eq!((1, 3), (game.shape_score(rock), game.shape_score(scissors)));
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let find = |x| game.find(x).unwrap();
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        for (winner, loser) in beats {
+            assert_eq!(
+                RockPaperScissorsResult::Win,
+                game.outcome(find(winner), find(loser))
+            );
+            assert_eq!(
+                RockPaperScissorsResult::Lost,
+                game.outcome(find(loser), find(winner))
+            );
+        }
+        assert_eq!(
+            vec!["Paper", "Spock"],
+            game.responses(find("Lizard"), &RockPaperScissorsResult::Lost)
+                .map(|x| game.name(x))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_score() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let find = |x| game.find(x).unwrap();
+
+        assert_eq!(2 + 6, game.score(find("Spock"), find("Scissors")));
+        assert_eq!(5, game.score(find("Scissors"), find("Spock")));
+        assert_eq!(4 + 3, game.score(find("Lizard"), find("Lizard")));
+    }
+
+    #[test]
+    fn test_responses_give_result() {
+        for n in [3, 5, 7, 9] {
+            let game = Game::new((0..n).map(|x| x.to_string())).unwrap();
+            for opponent in game.moves() {
+                for player in game.moves() {
+                    let result = game.outcome(player, opponent);
+                    assert!(game.responses(opponent, &result).any(|x| x == player));
+                }
+                for result in [
+                    RockPaperScissorsResult::Lost,
+                    RockPaperScissorsResult::Draw,
+                    RockPaperScissorsResult::Win,
+                ] {
+                    assert!(game
+                        .responses(opponent, &result)
+                        .all(|x| game.outcome(x, opponent) == result));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let game = Game::from_toml(r#"moves = ["Fire", "Water", "Air", "Earth", "Wood"]"#).unwrap();
+        assert_eq!(5, game.len());
+        assert_eq!("Air", game.name(game.get(2).unwrap()));
+
+        assert!(matches!(
+            Game::from_toml(r#"moves = ["Fire", "Water", "Air", "Earth"]"#),
+            Err(GameError::EvenNumberOfMoves(4))
+        ));
+        assert!(matches!(
+            Game::from_toml(r#"moves = ["Fire"]"#),
+            Err(GameError::TooFewMoves(1))
+        ));
+        assert!(matches!(
+            Game::from_toml(r#"moves = ["Fire", "Water", "Fire"]"#),
+            Err(GameError::DuplicateMove(x)) if x == "Fire"
+        ));
+        assert!(matches!(
+            Game::from_toml("moves = 3"),
+            Err(GameError::Toml(_))
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{AocError, LocatedError};
+use crate::solution::Solution;
+
+pub mod game;
+pub mod replay;
+pub mod rules;
+pub mod solver;
+pub mod tournament;
+
+use game::{Game, Move};
+use rules::RuleSet;
+
+static ROCK_PAPER_SCISSORS: LazyLock<Game> = LazyLock::new(Game::rock_paper_scissors);
+static DEFAULT_RULES: LazyLock<RuleSet> = LazyLock::new(RuleSet::default);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RockPaperScissorsResult {
+    Lost,
+    Draw,
+    Win,
+}
+
+impl RockPaperScissorsResult {
+    pub fn score(&self) -> usize {
+        match self {
+            RockPaperScissorsResult::Lost => 0,
+            RockPaperScissorsResult::Draw => 3,
+            RockPaperScissorsResult::Win => 6,
+        }
+    }
+}
+
+impl fmt::Display for RockPaperScissorsResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RockPaperScissorsResult::Lost => write!(f, "lost"),
+            RockPaperScissorsResult::Draw => write!(f, "draw"),
+            RockPaperScissorsResult::Win => write!(f, "win"),
+        }
+    }
+}
+
+impl FromStr for RockPaperScissorsResult {
+    type Err = RockPaperScissorsParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(RockPaperScissorsResult::Lost),
+            "Y" => Ok(RockPaperScissorsResult::Draw),
+            "Z" => Ok(RockPaperScissorsResult::Win),
+            _ => Err(RockPaperScissorsParseError),
+        }
+    }
+}
+
+// in the cyclic order of `Game::rock_paper_scissors`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RockPaperScissors {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RockPaperScissorsParseError;
+
+impl fmt::Display for RockPaperScissorsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rock paper scissors symbol")
+    }
+}
+
+impl std::error::Error for RockPaperScissorsParseError {}
+
+impl FromStr for RockPaperScissors {
+    type Err = RockPaperScissorsParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(RockPaperScissors::Rock),
+            "B" | "Y" => Ok(RockPaperScissors::Paper),
+            "C" | "Z" => Ok(RockPaperScissors::Scissor),
+            _ => Err(RockPaperScissorsParseError),
+        }
+    }
+}
+
+impl RockPaperScissors {
+    const ALL: [RockPaperScissors; 3] = [
+        RockPaperScissors::Rock,
+        RockPaperScissors::Paper,
+        RockPaperScissors::Scissor,
+    ];
+
+    pub fn to_move(self) -> Move {
+        ROCK_PAPER_SCISSORS
+            .get(self as usize)
+            .expect("every shape is a move of the game")
+    }
+
+    // none for moves of games with more than three moves
+    pub fn from_move(m: Move) -> Option<Self> {
+        RockPaperScissors::ALL.get(m.index()).copied()
+    }
+
+    pub fn score(&self) -> usize {
+        ROCK_PAPER_SCISSORS.shape_score(self.to_move())
+    }
+}
+
+// the second column only has to be a symbol of one of the player and result tables, it is
+// resolved when a part reads it
+#[derive(Debug, PartialEq, Eq)]
+pub struct StrategyGuide {
+    opponent: Move,
+    player_p1: Result<Move, Diagnostic>,
+    result_p2: Result<RockPaperScissorsResult, Diagnostic>,
+}
+
+impl StrategyGuide {
+    fn player_p1(&self) -> Result<Move, AocError> {
+        self.player_p1
+            .clone()
+            .map_err(|x| RockPaperScissorsParseError.at(x))
+    }
+
+    fn result_p2(&self) -> Result<RockPaperScissorsResult, AocError> {
+        self.result_p2
+            .clone()
+            .map_err(|x| RockPaperScissorsParseError.at(x))
+    }
+
+    fn score_p1(&self, rules: &RuleSet) -> Result<usize, AocError> {
+        Ok(rules.score(self.player_p1()?, self.opponent))
+    }
+
+    fn strategy_p2(&self, rules: &RuleSet) -> Result<Move, AocError> {
+        Ok(rules.game().response(self.opponent, &self.result_p2()?))
+    }
+
+    fn score_p2(&self, rules: &RuleSet) -> Result<usize, AocError> {
+        Ok(rules.shape_score(self.strategy_p2(rules)?) + rules.outcome_score(&self.result_p2()?))
+    }
+}
+
+fn symbol<T>(input: &str, found: &str, value: Option<T>, expected: String) -> Result<T, AocError> {
+    value.ok_or_else(|| RockPaperScissorsParseError.at(Diagnostic::new(input, found, expected)))
+}
+
+pub fn parse(input: &str, rules: &RuleSet) -> Result<Vec<StrategyGuide>, AocError> {
+    input
+        .lines()
+        .map(str::trim)
+        .map(|x| {
+            x.split_once(' ').ok_or_else(|| {
+                AocError::InvalidFormat(Diagnostic::new(input, x, "`<opponent> <player>`"))
+            })
+        })
+        .map(|line| {
+            let (a, b) = line?;
+            let (a, b) = (a.trim(), b.trim());
+            let opponent = symbol(input, a, rules.opponent(a), rules.expected_opponent())?;
+            let (player_p1, result_p2) = (rules.player(b), rules.result(b));
+            let missing = |expected| Diagnostic::new(input, b, expected);
+            if player_p1.is_none() && result_p2.is_none() {
+                return Err(RockPaperScissorsParseError.at(missing(rules.expected_second_column())));
+            }
+            Ok(StrategyGuide {
+                opponent,
+                player_p1: player_p1.ok_or_else(|| missing(rules.expected_player())),
+                result_p2: result_p2.ok_or_else(|| missing(rules.expected_result())),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(input: &[StrategyGuide], rules: &RuleSet) -> Result<usize, AocError> {
+    input.iter().map(|x| x.score_p1(rules)).sum()
+}
+
+pub fn part2(input: &[StrategyGuide], rules: &RuleSet) -> Result<usize, AocError> {
+    input.iter().map(|x| x.score_p2(rules)).sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 2;
+    type Input = Vec<StrategyGuide>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input, &DEFAULT_RULES)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input, &DEFAULT_RULES)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input, &DEFAULT_RULES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    const TEST_INPUT: &str = "A Y
+    B X
+    C Z";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![
+                StrategyGuide {
+                    opponent: RockPaperScissors::Rock.to_move(),
+                    player_p1: Ok(RockPaperScissors::Paper.to_move()),
+                    result_p2: Ok(RockPaperScissorsResult::Draw)
+                },
+                StrategyGuide {
+                    opponent: RockPaperScissors::Paper.to_move(),
+                    player_p1: Ok(RockPaperScissors::Rock.to_move()),
+                    result_p2: Ok(RockPaperScissorsResult::Lost)
+                },
+                StrategyGuide {
+                    opponent: RockPaperScissors::Scissor.to_move(),
+                    player_p1: Ok(RockPaperScissors::Scissor.to_move()),
+                    result_p2: Ok(RockPaperScissorsResult::Win)
+                }
+            ],
+            parse(TEST_INPUT, &DEFAULT_RULES).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("A Y\nB W", &DEFAULT_RULES).unwrap_err();
+        assert_eq!(
+            AocError::RockPaperScissors(
+                Diagnostic {
+                    span: 6..7,
+                    line: 2,
+                    column: 3,
+                    source_line: "B W".to_string(),
+                    found: "W".to_string(),
+                    expected: "one of `X`, `Y`, `Z`".to_string(),
+                },
+                RockPaperScissorsParseError
+            ),
+            err
+        );
+
+        let err = parse("AY", &DEFAULT_RULES).unwrap_err();
+        assert_eq!(
+            "invalid format at line 1, column 1: expected `<opponent> <player>`, found `AY`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let rules = RuleSet::from_toml(
+            r#"
+outcome_scores = { lost = 0, draw = 1, win = 2 }
+
+[opponent]
+R = "Rock"
+P = "Paper"
+S = "Scissors"
+"#,
+        )
+        .unwrap();
+        let guide = parse("R Y\nP X\nS Z", &rules).unwrap();
+
+        assert_eq!(4 + 1 + 4, part1(&guide, &rules).unwrap());
+        assert_eq!(2 + 1 + 3, part2(&guide, &rules).unwrap());
+        assert_eq!(
+            "invalid rock paper scissors symbol at line 1, column 1: expected one of `P`, `R`, `S`, found `A`",
+            parse("A Y", &rules).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_separate_player_and_result_symbols() {
+        let rules = RuleSet::from_toml(
+            r#"
+[player]
+R = "Rock"
+P = "Paper"
+S = "Scissors"
+"#,
+        )
+        .unwrap();
+
+        let guide = parse("A P\nB R\nC S", &rules).unwrap();
+        assert_eq!(15, part1(&guide, &rules).unwrap());
+        assert_eq!(
+            "invalid rock paper scissors symbol at line 1, column 3: expected one of `X`, `Y`, `Z`, found `P`",
+            part2(&guide, &rules).unwrap_err().to_string()
+        );
+
+        let guide = parse("A Y\nB X\nC Z", &rules).unwrap();
+        assert_eq!(12, part2(&guide, &rules).unwrap());
+        assert!(part1(&guide, &rules).is_err());
+
+        assert_eq!(
+            "invalid rock paper scissors symbol at line 1, column 3: expected one of `P`, `R`, `S`, `X`, `Y`, `Z`, found `W`",
+            parse("A W", &rules).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            15,
+            part1(&parse(TEST_INPUT, &DEFAULT_RULES).unwrap(), &DEFAULT_RULES).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            12,
+            part2(&parse(TEST_INPUT, &DEFAULT_RULES).unwrap(), &DEFAULT_RULES).unwrap()
+        );
+    }
+}
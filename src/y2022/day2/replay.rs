@@ -0,0 +1,218 @@
+use super::game::Move;
+use super::rules::RuleSet;
+use super::{RockPaperScissorsResult, StrategyGuide};
+use crate::csv;
+use crate::error::AocError;
+
+// how the second column of the guide is read: the move to play in part 1 or the result to
+// reach in part 2
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Interpretation {
+    Part1,
+    Part2,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Round {
+    // starting at 1
+    pub round: usize,
+    pub opponent: Move,
+    pub player: Move,
+    pub result: RockPaperScissorsResult,
+    pub shape_score: usize,
+    pub outcome_score: usize,
+    // score of this and all previous rounds
+    pub total: usize,
+}
+
+fn play(
+    x: &StrategyGuide,
+    rules: &RuleSet,
+    interpretation: Interpretation,
+) -> Result<(Move, RockPaperScissorsResult), AocError> {
+    Ok(match interpretation {
+        Interpretation::Part1 => {
+            let player = x.player_p1()?;
+            (player, rules.game().outcome(player, x.opponent))
+        }
+        Interpretation::Part2 => (x.strategy_p2(rules)?, x.result_p2()?),
+    })
+}
+
+// fails on the first round whose second column is not a symbol of `interpretation`
+pub fn replay<'a>(
+    guide: &'a [StrategyGuide],
+    rules: &'a RuleSet,
+    interpretation: Interpretation,
+) -> impl Iterator<Item = Result<Round, AocError>> + 'a {
+    guide.iter().enumerate().scan(0, move |total, (idx, x)| {
+        let (player, result) = match play(x, rules, interpretation) {
+            Ok(x) => x,
+            Err(e) => return Some(Err(e)),
+        };
+        let shape_score = rules.shape_score(player);
+        let outcome_score = rules.outcome_score(&result);
+        *total += shape_score + outcome_score;
+
+        Some(Ok(Round {
+            round: idx + 1,
+            opponent: x.opponent,
+            player,
+            result,
+            shape_score,
+            outcome_score,
+            total: *total,
+        }))
+    })
+}
+
+const COLUMNS: [&str; 7] = [
+    "round", "opponent", "player", "result", "shape", "outcome", "total",
+];
+
+fn fields(round: &Round, rules: &RuleSet) -> [String; 7] {
+    [
+        round.round.to_string(),
+        rules.game().name(round.opponent).to_string(),
+        rules.game().name(round.player).to_string(),
+        round.result.to_string(),
+        round.shape_score.to_string(),
+        round.outcome_score.to_string(),
+        round.total.to_string(),
+    ]
+}
+
+pub fn render_table(rounds: &[Round], rules: &RuleSet) -> String {
+    let rows: Vec<_> = rounds.iter().map(|x| fields(x, rules)).collect();
+    let widths: Vec<_> = (0..COLUMNS.len())
+        .map(|idx| {
+            rows.iter()
+                .map(|x| x[idx].len())
+                .chain([COLUMNS[idx].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    // names are left aligned, numbers right aligned
+    let line = |cells: &[&str]| {
+        let cells: Vec<_> = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(idx, (cell, width))| match idx {
+                1..=3 => format!("{:<w$}", cell, w = width),
+                _ => format!("{:>w$}", cell, w = width),
+            })
+            .collect();
+        cells.join(" | ").trim_end().to_string() + "\n"
+    };
+
+    let mut table = line(&COLUMNS);
+    for row in &rows {
+        table.push_str(&line(&row.each_ref().map(String::as_str)));
+    }
+    table
+}
+
+pub fn to_csv(rounds: &[Round], rules: &RuleSet) -> String {
+    let mut csv = csv::record(COLUMNS);
+    for round in rounds {
+        csv.push_str(&csv::record(fields(round, rules)));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+
+    const TEST_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn test_replay() {
+        let rules = RuleSet::default();
+        let guide = parse(TEST_INPUT, &rules).unwrap();
+
+        let rounds: Vec<_> = replay(&guide, &rules, Interpretation::Part1)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![8, 9, 15],
+            rounds.iter().map(|x| x.total).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Round {
+                round: 2,
+                opponent: rules.opponent("B").unwrap(),
+                player: rules.player("X").unwrap(),
+                result: RockPaperScissorsResult::Lost,
+                shape_score: 1,
+                outcome_score: 0,
+                total: 9,
+            },
+            rounds[1]
+        );
+
+        let rounds: Vec<_> = replay(&guide, &rules, Interpretation::Part2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![4, 5, 12],
+            rounds.iter().map(|x| x.total).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let rules = RuleSet::default();
+        let guide = parse(TEST_INPUT, &rules).unwrap();
+        let rounds: Vec<_> = replay(&guide, &rules, Interpretation::Part2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "round | opponent | player | result | shape | outcome | total",
+                "    1 | Rock     | Rock   | draw   |     1 |       3 |     4",
+                "    2 | Paper    | Rock   | lost   |     1 |       0 |     5",
+                "    3 | Scissors | Rock   | win    |     1 |       6 |    12",
+            ],
+            render_table(&rounds, &rules).lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let rules = RuleSet::from_toml(
+            r#"
+moves = ["Rock", "Paper, folded", "Scissors"]
+
+[opponent]
+A = "Rock"
+B = "Paper, folded"
+C = "Scissors"
+
+[player]
+X = "Rock"
+Y = "Paper, folded"
+Z = "Scissors"
+"#,
+        )
+        .unwrap();
+        let guide = parse(TEST_INPUT, &rules).unwrap();
+        let rounds: Vec<_> = replay(&guide, &rules, Interpretation::Part1)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            "\
+round,opponent,player,result,shape,outcome,total
+1,Rock,\"Paper, folded\",win,2,6,8
+2,\"Paper, folded\",Rock,lost,1,0,9
+3,Scissors,Scissors,draw,3,3,15
+",
+            to_csv(&rounds, &rules)
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::game::{Game, GameError, Move};
+use super::RockPaperScissorsResult;
+
+#[derive(Debug)]
+pub enum RuleError {
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    Game(GameError),
+    UnknownMove(String),
+    MissingTable(&'static str),
+    MissingShapeScore(String),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::Toml(err) => write!(f, "{}", err),
+            RuleError::Json(err) => write!(f, "{}", err),
+            RuleError::Game(err) => write!(f, "{}", err),
+            RuleError::UnknownMove(name) => write!(f, "unknown move `{}`", name),
+            RuleError::MissingTable(table) => write!(
+                f,
+                "`{}` has to be given for games other than rock paper scissors",
+                table
+            ),
+            RuleError::MissingShapeScore(name) => {
+                write!(f, "move `{}` has no shape score", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+impl From<toml::de::Error> for RuleError {
+    fn from(err: toml::de::Error) -> Self {
+        RuleError::Toml(err)
+    }
+}
+
+impl From<serde_json::Error> for RuleError {
+    fn from(err: serde_json::Error) -> Self {
+        RuleError::Json(err)
+    }
+}
+
+impl From<GameError> for RuleError {
+    fn from(err: GameError) -> Self {
+        RuleError::Game(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize)]
+pub struct OutcomeScores {
+    pub lost: usize,
+    pub draw: usize,
+    pub win: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConfig {
+    moves: Option<Vec<String>>,
+    opponent: Option<BTreeMap<String, String>>,
+    player: Option<BTreeMap<String, String>>,
+    result: Option<BTreeMap<String, RockPaperScissorsResult>>,
+    shape_scores: Option<BTreeMap<String, usize>>,
+    outcome_scores: Option<OutcomeScores>,
+}
+
+// how a strategy guide is read and scored, every entry is optional and defaults to the
+// rules of the puzzle, e.g. in TOML
+//
+// moves = ["Rock", "Paper", "Scissors"]
+// outcome_scores = { lost = 0, draw = 3, win = 6 }
+//
+// [opponent]
+// A = "Rock"
+// B = "Paper"
+// C = "Scissors"
+//
+// [player]
+// X = "Rock"
+// Y = "Paper"
+// Z = "Scissors"
+//
+// [result]
+// X = "lost"
+// Y = "draw"
+// Z = "win"
+//
+// [shape_scores]
+// Rock = 1
+// Paper = 2
+// Scissors = 3
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RuleSet {
+    game: Game,
+    opponent: BTreeMap<String, Move>,
+    player: BTreeMap<String, Move>,
+    result: BTreeMap<String, RockPaperScissorsResult>,
+    shape_scores: Vec<usize>,
+    outcome_scores: OutcomeScores,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::from_config(RuleConfig {
+            moves: None,
+            opponent: None,
+            player: None,
+            result: None,
+            shape_scores: None,
+            outcome_scores: None,
+        })
+        .expect("default rules are valid")
+    }
+}
+
+fn symbols(pairs: [(&str, &str); 3]) -> BTreeMap<String, String> {
+    pairs
+        .into_iter()
+        .map(|(symbol, name)| (symbol.to_string(), name.to_string()))
+        .collect()
+}
+
+impl RuleSet {
+    pub fn from_toml(input: &str) -> Result<Self, RuleError> {
+        RuleSet::from_config(toml::from_str(input)?)
+    }
+
+    pub fn from_json(input: &str) -> Result<Self, RuleError> {
+        RuleSet::from_config(serde_json::from_str(input)?)
+    }
+
+    fn from_config(config: RuleConfig) -> Result<Self, RuleError> {
+        let game = match config.moves {
+            Some(moves) => Game::new(moves)?,
+            None => Game::rock_paper_scissors(),
+        };
+        // the symbol tables of the puzzle only apply to its game, even if it is listed
+        let default_game = game == Game::rock_paper_scissors();
+
+        let table = |table: Option<BTreeMap<String, String>>, name, default| {
+            let table = match table {
+                Some(table) => table,
+                None if default_game => symbols(default),
+                None => return Err(RuleError::MissingTable(name)),
+            };
+            table
+                .into_iter()
+                .map(|(symbol, name)| match game.find(&name) {
+                    Some(m) => Ok((symbol, m)),
+                    None => Err(RuleError::UnknownMove(name)),
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()
+        };
+        let opponent = table(
+            config.opponent,
+            "opponent",
+            [("A", "Rock"), ("B", "Paper"), ("C", "Scissors")],
+        )?;
+        let player = table(
+            config.player,
+            "player",
+            [("X", "Rock"), ("Y", "Paper"), ("Z", "Scissors")],
+        )?;
+
+        let shape_scores = match config.shape_scores {
+            Some(scores) => {
+                if let Some(name) = scores.keys().find(|x| game.find(x).is_none()) {
+                    return Err(RuleError::UnknownMove(name.clone()));
+                }
+                game.moves()
+                    .map(|m| {
+                        scores
+                            .get(game.name(m))
+                            .copied()
+                            .ok_or_else(|| RuleError::MissingShapeScore(game.name(m).to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            None => game.moves().map(|m| game.shape_score(m)).collect(),
+        };
+
+        Ok(RuleSet {
+            opponent,
+            player,
+            result: config.result.unwrap_or_else(|| {
+                [
+                    ("X", RockPaperScissorsResult::Lost),
+                    ("Y", RockPaperScissorsResult::Draw),
+                    ("Z", RockPaperScissorsResult::Win),
+                ]
+                .into_iter()
+                .map(|(symbol, result)| (symbol.to_string(), result))
+                .collect()
+            }),
+            shape_scores,
+            outcome_scores: config.outcome_scores.unwrap_or(OutcomeScores {
+                lost: RockPaperScissorsResult::Lost.score(),
+                draw: RockPaperScissorsResult::Draw.score(),
+                win: RockPaperScissorsResult::Win.score(),
+            }),
+            game,
+        })
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn opponent(&self, symbol: &str) -> Option<Move> {
+        self.opponent.get(symbol).copied()
+    }
+
+    pub fn player(&self, symbol: &str) -> Option<Move> {
+        self.player.get(symbol).copied()
+    }
+
+    pub fn result(&self, symbol: &str) -> Option<RockPaperScissorsResult> {
+        self.result.get(symbol).copied()
+    }
+
+    // descriptions of the valid symbols for diagnostics, like "one of `A`, `B`, `C`"
+    pub fn expected_opponent(&self) -> String {
+        one_of(self.opponent.keys())
+    }
+
+    pub fn expected_player(&self) -> String {
+        one_of(self.player.keys())
+    }
+
+    pub fn expected_result(&self) -> String {
+        one_of(self.result.keys())
+    }
+
+    // the symbols of the player and result tables, a guide can use either of them
+    pub fn expected_second_column(&self) -> String {
+        let symbols: BTreeSet<_> = self.player.keys().chain(self.result.keys()).collect();
+        one_of(symbols.into_iter())
+    }
+
+    pub fn shape_score(&self, m: Move) -> usize {
+        self.shape_scores[m.index()]
+    }
+
+    pub fn outcome_score(&self, result: &RockPaperScissorsResult) -> usize {
+        match result {
+            RockPaperScissorsResult::Lost => self.outcome_scores.lost,
+            RockPaperScissorsResult::Draw => self.outcome_scores.draw,
+            RockPaperScissorsResult::Win => self.outcome_scores.win,
+        }
+    }
+
+    pub fn score(&self, player: Move, opponent: Move) -> usize {
+        self.shape_score(player) + self.outcome_score(&self.game.outcome(player, opponent))
+    }
+}
+
+fn one_of<'a>(symbols: impl Iterator<Item = &'a String>) -> String {
+    let symbols: Vec<_> = symbols.map(|x| format!("`{}`", x)).collect();
+    format!("one of {}", symbols.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let rules = RuleSet::default();
+        let game = rules.game();
+        let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"].map(|x| game.find(x).unwrap());
+
+        assert_eq!(Some(rock), rules.opponent("A"));
+        assert_eq!(Some(scissors), rules.player("Z"));
+        assert_eq!(None, rules.player("A"));
+        assert_eq!(Some(RockPaperScissorsResult::Draw), rules.result("Y"));
+        assert_eq!(8, rules.score(paper, rock));
+        assert_eq!("one of `X`, `Y`, `Z`", rules.expected_player());
+        assert_eq!(Ok(rules), RuleSet::from_toml("").map_err(|e| e.to_string()));
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let rules = RuleSet::from_toml(
+            r#"
+outcome_scores = { lost = 1, draw = 2, win = 10 }
+
+[player]
+R = "Rock"
+P = "Paper"
+S = "Scissors"
+
+[shape_scores]
+Rock = 0
+Paper = 0
+Scissors = 5
+"#,
+        )
+        .unwrap();
+        let game = rules.game();
+
+        assert_eq!(game.find("Paper"), rules.player("P"));
+        assert_eq!(None, rules.player("X"));
+        assert_eq!(
+            15,
+            rules.score(rules.player("S").unwrap(), rules.opponent("B").unwrap())
+        );
+        assert_eq!(
+            1,
+            rules.score(rules.player("R").unwrap(), rules.opponent("B").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let rules = RuleSet::from_json(
+            r#"{
+                "moves": ["Rock", "Spock", "Paper", "Lizard", "Scissors"],
+                "opponent": {"A": "Rock", "B": "Spock", "C": "Paper", "D": "Lizard", "E": "Scissors"},
+                "player": {"V": "Rock", "W": "Spock", "X": "Paper", "Y": "Lizard", "Z": "Scissors"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(5, rules.game().len());
+        assert_eq!(
+            2 + 6,
+            rules.score(rules.player("W").unwrap(), rules.opponent("E").unwrap())
+        );
+        assert_eq!(Some(RockPaperScissorsResult::Win), rules.result("Z"));
+    }
+
+    #[test]
+    fn test_listed_default_game() {
+        assert_eq!(
+            RuleSet::default(),
+            RuleSet::from_toml("moves = [\"Rock\", \"Paper\", \"Scissors\"]").unwrap()
+        );
+        assert!(matches!(
+            RuleSet::from_toml("moves = [\"Rock\", \"Scissors\", \"Paper\"]"),
+            Err(RuleError::MissingTable("opponent"))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(matches!(
+            RuleSet::from_toml("[player]\nX = \"Spock\""),
+            Err(RuleError::UnknownMove(x)) if x == "Spock"
+        ));
+        assert!(matches!(
+            RuleSet::from_toml("moves = [\"a\", \"b\", \"c\"]"),
+            Err(RuleError::MissingTable("opponent"))
+        ));
+        assert!(matches!(
+            RuleSet::from_toml("[shape_scores]\nRock = 1"),
+            Err(RuleError::MissingShapeScore(x)) if x == "Paper"
+        ));
+        assert!(matches!(
+            RuleSet::from_toml("moves = [\"a\", \"b\"]"),
+            Err(RuleError::Game(GameError::TooFewMoves(2)))
+        ));
+        assert!(matches!(
+            RuleSet::from_toml("scores = 1"),
+            Err(RuleError::Toml(_))
+        ));
+        assert!(matches!(RuleSet::from_json("{"), Err(RuleError::Json(_))));
+    }
+}
@@ -0,0 +1,174 @@
+use super::game::Move;
+use super::rules::RuleSet;
+use super::StrategyGuide;
+use crate::error::AocError;
+
+// the move with the highest score against `opponent` and its score, the first such move in
+// the order of the game on ties
+pub fn best_response(rules: &RuleSet, opponent: Move) -> (Move, usize) {
+    rules
+        .game()
+        .moves()
+        .map(|m| (m, rules.score(m, opponent)))
+        .fold(None, |best: Option<(Move, usize)>, (m, score)| match best {
+            Some((_, best_score)) if best_score >= score => best,
+            _ => Some((m, score)),
+        })
+        .expect("a game has at least three moves")
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RoundAnalysis {
+    pub opponent: Move,
+    pub best: Move,
+    pub best_score: usize,
+    pub p1: Move,
+    pub p1_score: usize,
+    pub p2: Move,
+    pub p2_score: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    pub rounds: Vec<RoundAnalysis>,
+    pub optimal_total: usize,
+    pub p1_total: usize,
+    pub p2_total: usize,
+}
+
+impl Analysis {
+    // how many points the interpretations of part 1 and part 2 miss compared to the optimum
+    pub fn p1_gap(&self) -> usize {
+        self.optimal_total - self.p1_total
+    }
+
+    pub fn p2_gap(&self) -> usize {
+        self.optimal_total - self.p2_total
+    }
+
+    pub fn render_table(&self, rules: &RuleSet) -> String {
+        let name = |m| rules.game().name(m);
+        let width = rules
+            .game()
+            .moves()
+            .map(|m| name(m).len())
+            .chain(["opponent".len()])
+            .max()
+            .unwrap_or(0);
+
+        let mut table = format!(
+            "{:>5} | {:<w$} | {:<w$} | {:>5} | {:<w$} | {:>5} | {:<w$} | {:>5}\n",
+            "round",
+            "opponent",
+            "best",
+            "score",
+            "part 1",
+            "score",
+            "part 2",
+            "score",
+            w = width
+        );
+        for (idx, x) in self.rounds.iter().enumerate() {
+            table.push_str(&format!(
+                "{:>5} | {:<w$} | {:<w$} | {:>5} | {:<w$} | {:>5} | {:<w$} | {:>5}\n",
+                idx + 1,
+                name(x.opponent),
+                name(x.best),
+                x.best_score,
+                name(x.p1),
+                x.p1_score,
+                name(x.p2),
+                x.p2_score,
+                w = width
+            ));
+        }
+        table.push_str(&format!(
+            "\noptimal: {}\npart 1: {} ({} below optimal)\npart 2: {} ({} below optimal)\n",
+            self.optimal_total,
+            self.p1_total,
+            self.p1_gap(),
+            self.p2_total,
+            self.p2_gap()
+        ));
+        table
+    }
+}
+
+// compares both interpretations of every round of `guide` with the best response
+pub fn analyse(guide: &[StrategyGuide], rules: &RuleSet) -> Result<Analysis, AocError> {
+    let rounds: Vec<_> = guide
+        .iter()
+        .map(|x| {
+            let (best, best_score) = best_response(rules, x.opponent);
+            Ok(RoundAnalysis {
+                opponent: x.opponent,
+                best,
+                best_score,
+                p1: x.player_p1()?,
+                p1_score: x.score_p1(rules)?,
+                p2: x.strategy_p2(rules)?,
+                p2_score: x.score_p2(rules)?,
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    Ok(Analysis {
+        optimal_total: rounds.iter().map(|x| x.best_score).sum(),
+        p1_total: rounds.iter().map(|x| x.p1_score).sum(),
+        p2_total: rounds.iter().map(|x| x.p2_score).sum(),
+        rounds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+
+    #[test]
+    fn test_best_response() {
+        let rules = RuleSet::default();
+        let find = |x| rules.game().find(x).unwrap();
+
+        assert_eq!((find("Paper"), 8), best_response(&rules, find("Rock")));
+        assert_eq!((find("Rock"), 7), best_response(&rules, find("Scissors")));
+
+        // with a huge shape score for rock, losing with rock beats winning with anything else
+        let rules =
+            RuleSet::from_toml("[shape_scores]\nRock = 10\nPaper = 2\nScissors = 3").unwrap();
+        assert_eq!((find("Rock"), 10), best_response(&rules, find("Paper")));
+    }
+
+    #[test]
+    fn test_analyse() {
+        let rules = RuleSet::default();
+        let analysis = analyse(&parse("A Y\nB X\nC Z", &rules).unwrap(), &rules).unwrap();
+
+        assert_eq!(
+            vec![8, 9, 7],
+            analysis
+                .rounds
+                .iter()
+                .map(|x| x.best_score)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (24, 15, 12),
+            (analysis.optimal_total, analysis.p1_total, analysis.p2_total)
+        );
+        assert_eq!((9, 12), (analysis.p1_gap(), analysis.p2_gap()));
+        assert_eq!(
+            vec![
+                "round | opponent | best     | score | part 1   | score | part 2   | score",
+                "    1 | Rock     | Paper    |     8 | Paper    |     8 | Rock     |     4",
+                "    2 | Paper    | Scissors |     9 | Rock     |     1 | Rock     |     1",
+                "    3 | Scissors | Rock     |     7 | Scissors |     6 | Rock     |     7",
+                "",
+                "optimal: 24",
+                "part 1: 15 (9 below optimal)",
+                "part 2: 12 (12 below optimal)",
+            ],
+            analysis.render_table(&rules).lines().collect::<Vec<_>>()
+        );
+    }
+}
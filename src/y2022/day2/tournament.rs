@@ -0,0 +1,475 @@
+use std::fmt;
+
+use super::replay::Interpretation;
+use super::rules::RuleSet;
+use super::{RockPaperScissors, RockPaperScissorsResult, StrategyGuide, ROCK_PAPER_SCISSORS};
+use crate::error::AocError;
+
+// SplitMix64, small and good enough to make simulations reproducible from a seed
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in `0..n`, `n` has to be positive
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Strategy {
+    // plays the moves in order and starts over at the end
+    Fixed(Vec<RockPaperScissors>),
+    Random,
+    // beats the move the opponent played most often so far
+    Frequency,
+    // beats the move the opponent played most often after its last move
+    Markov,
+}
+
+const NOT_ROCK_PAPER_SCISSORS: AocError =
+    AocError::NoSolution("a guide strategy needs the game of rock paper scissors");
+
+impl Strategy {
+    // the moves of the player column of `guide`, parsed with `rules`, as read by
+    // `interpretation`, the game of `rules` has to be rock paper scissors
+    pub fn from_guide(
+        guide: &[StrategyGuide],
+        rules: &RuleSet,
+        interpretation: Interpretation,
+    ) -> Result<Strategy, AocError> {
+        // the moves are mapped by their position, which only matches the shapes in this game
+        if *rules.game() != *ROCK_PAPER_SCISSORS {
+            return Err(NOT_ROCK_PAPER_SCISSORS);
+        }
+        super::replay::replay(guide, rules, interpretation)
+            .map(|x| RockPaperScissors::from_move(x?.player).ok_or(NOT_ROCK_PAPER_SCISSORS))
+            .collect::<Result<_, _>>()
+            .map(Strategy::Fixed)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Fixed(_) => "fixed",
+            Strategy::Random => "random",
+            Strategy::Frequency => "frequency",
+            Strategy::Markov => "markov",
+        }
+    }
+}
+
+fn beats(m: RockPaperScissors) -> RockPaperScissors {
+    RockPaperScissors::from_move(
+        ROCK_PAPER_SCISSORS.response(m.to_move(), &RockPaperScissorsResult::Win),
+    )
+    .expect("every move of rock paper scissors is a shape")
+}
+
+// the first move with the highest count
+fn most_frequent(counts: &[usize; 3]) -> Option<RockPaperScissors> {
+    let (idx, count) =
+        counts.iter().enumerate().fold(
+            (0, 0),
+            |best, (idx, &count)| {
+                if count > best.1 {
+                    (idx, count)
+                } else {
+                    best
+                }
+            },
+        );
+    (count > 0).then_some(RockPaperScissors::ALL[idx])
+}
+
+// the state of a strategy during a single match
+struct Player<'a> {
+    strategy: &'a Strategy,
+    round: usize,
+    counts: [usize; 3],
+    transitions: [[usize; 3]; 3],
+    last_opponent: Option<RockPaperScissors>,
+}
+
+impl<'a> Player<'a> {
+    fn new(strategy: &'a Strategy) -> Self {
+        Player {
+            strategy,
+            round: 0,
+            counts: [0; 3],
+            transitions: [[0; 3]; 3],
+            last_opponent: None,
+        }
+    }
+
+    fn choose(&self, rng: &mut Rng) -> RockPaperScissors {
+        let random = |rng: &mut Rng| RockPaperScissors::ALL[rng.below(3)];
+        let prediction = match self.strategy {
+            Strategy::Fixed(moves) if !moves.is_empty() => {
+                return moves[self.round % moves.len()];
+            }
+            Strategy::Fixed(_) | Strategy::Random => None,
+            Strategy::Frequency => most_frequent(&self.counts),
+            Strategy::Markov => self
+                .last_opponent
+                .and_then(|x| most_frequent(&self.transitions[x as usize])),
+        };
+        prediction.map_or_else(|| random(rng), beats)
+    }
+
+    fn observe(&mut self, opponent: RockPaperScissors) {
+        self.round += 1;
+        self.counts[opponent as usize] += 1;
+        if let Some(last) = self.last_opponent {
+            self.transitions[last as usize][opponent as usize] += 1;
+        }
+        self.last_opponent = Some(opponent);
+    }
+}
+
+fn score(
+    player: RockPaperScissors,
+    opponent: RockPaperScissors,
+) -> (RockPaperScissorsResult, usize) {
+    let result = ROCK_PAPER_SCISSORS.outcome(player.to_move(), opponent.to_move());
+    (result, player.score() + result.score())
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ScoreSummary {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl ScoreSummary {
+    fn new(totals: &[usize]) -> Option<Self> {
+        let n = totals.len() as f64;
+        let mean = totals.iter().sum::<usize>() as f64 / n;
+        let variance = totals
+            .iter()
+            .map(|x| (*x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(ScoreSummary {
+            min: *totals.iter().min()?,
+            max: *totals.iter().max()?,
+            mean,
+            std_dev: variance.sqrt(),
+        })
+    }
+}
+
+// results of all matches between two strategies, rounds are counted from the player's side
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatchupReport {
+    pub player: &'static str,
+    pub opponent: &'static str,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    // distributions of the totals of all matches
+    pub player_scores: ScoreSummary,
+    pub opponent_scores: ScoreSummary,
+}
+
+impl MatchupReport {
+    fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.rounds() as f64
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.draws as f64 / self.rounds() as f64
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.losses as f64 / self.rounds() as f64
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TournamentConfig {
+    pub matches: usize,
+    pub rounds: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EmptyTournament;
+
+impl fmt::Display for EmptyTournament {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a tournament needs at least one match of at least one round"
+        )
+    }
+}
+
+impl std::error::Error for EmptyTournament {}
+
+pub fn play_matchup(
+    player: &Strategy,
+    opponent: &Strategy,
+    config: &TournamentConfig,
+    rng: &mut Rng,
+) -> Result<MatchupReport, EmptyTournament> {
+    if config.matches == 0 || config.rounds == 0 {
+        return Err(EmptyTournament);
+    }
+
+    let (mut wins, mut draws, mut losses) = (0, 0, 0);
+    let mut player_totals = Vec::with_capacity(config.matches);
+    let mut opponent_totals = Vec::with_capacity(config.matches);
+    for _ in 0..config.matches {
+        let (mut a, mut b) = (Player::new(player), Player::new(opponent));
+        let (mut total_a, mut total_b) = (0, 0);
+        for _ in 0..config.rounds {
+            let (move_a, move_b) = (a.choose(rng), b.choose(rng));
+            let (result, score_a) = score(move_a, move_b);
+            let (_, score_b) = score(move_b, move_a);
+            match result {
+                RockPaperScissorsResult::Win => wins += 1,
+                RockPaperScissorsResult::Draw => draws += 1,
+                RockPaperScissorsResult::Lost => losses += 1,
+            }
+            total_a += score_a;
+            total_b += score_b;
+            a.observe(move_b);
+            b.observe(move_a);
+        }
+        player_totals.push(total_a);
+        opponent_totals.push(total_b);
+    }
+
+    Ok(MatchupReport {
+        player: player.name(),
+        opponent: opponent.name(),
+        wins,
+        draws,
+        losses,
+        player_scores: ScoreSummary::new(&player_totals).ok_or(EmptyTournament)?,
+        opponent_scores: ScoreSummary::new(&opponent_totals).ok_or(EmptyTournament)?,
+    })
+}
+
+// every strategy plays every other strategy once, with a single rng seeded from the config
+pub fn tournament(
+    strategies: &[Strategy],
+    config: &TournamentConfig,
+) -> Result<Vec<MatchupReport>, EmptyTournament> {
+    let mut rng = Rng::new(config.seed);
+    let mut reports = vec![];
+    for (idx, player) in strategies.iter().enumerate() {
+        for opponent in &strategies[idx + 1..] {
+            reports.push(play_matchup(player, opponent, config, &mut rng)?);
+        }
+    }
+    Ok(reports)
+}
+
+pub fn render_table(reports: &[MatchupReport]) -> String {
+    let mut table = format!(
+        "{:<9} | {:<9} | {:>6} | {:>6} | {:>6} | {:>25} | {:>25}\n",
+        "player", "opponent", "win", "draw", "loss", "player score", "opponent score"
+    );
+    let summary =
+        |x: &ScoreSummary| format!("{:.1} ± {:.1} [{}, {}]", x.mean, x.std_dev, x.min, x.max);
+    for x in reports {
+        table.push_str(&format!(
+            "{:<9} | {:<9} | {:>5.1}% | {:>5.1}% | {:>5.1}% | {:>25} | {:>25}\n",
+            x.player,
+            x.opponent,
+            x.win_rate() * 100.0,
+            x.draw_rate() * 100.0,
+            x.loss_rate() * 100.0,
+            summary(&x.player_scores),
+            summary(&x.opponent_scores)
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: TournamentConfig = TournamentConfig {
+        matches: 10,
+        rounds: 100,
+        seed: 42,
+    };
+
+    #[test]
+    fn test_render_table() {
+        let summary = |mean| ScoreSummary {
+            min: 400,
+            max: 600,
+            mean,
+            std_dev: 12.25,
+        };
+        let report = MatchupReport {
+            player: "markov",
+            opponent: "random",
+            wins: 50,
+            draws: 25,
+            losses: 25,
+            player_scores: summary(512.5),
+            opponent_scores: summary(450.0),
+        };
+
+        assert_eq!(
+            vec![
+                "player    | opponent  |    win |   draw |   loss |              player score |            opponent score",
+                "markov    | random    |  50.0% |  25.0% |  25.0% |   512.5 ± 12.2 [400, 600] |   450.0 ± 12.2 [400, 600]",
+            ],
+            render_table(&[report]).lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+        assert_eq!(0xe220_a839_7b1d_cdaf, rng.next_u64());
+
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            counts[rng.below(3)] += 1;
+        }
+        assert!(counts.iter().all(|x| (900..1100).contains(x)));
+    }
+
+    #[test]
+    fn test_frequency_beats_fixed() {
+        let fixed = Strategy::Fixed(vec![RockPaperScissors::Rock]);
+        let report = play_matchup(&Strategy::Frequency, &fixed, &CONFIG, &mut Rng::new(1)).unwrap();
+
+        // only the first round of every match is a guess
+        assert!(report.wins >= CONFIG.matches * (CONFIG.rounds - 1));
+        assert!(report.player_scores.min >= 8 * (CONFIG.rounds - 1));
+        assert!(report.opponent_scores.max <= CONFIG.rounds - 1 + 7);
+    }
+
+    #[test]
+    fn test_markov_beats_cycle() {
+        let cycle = Strategy::Fixed(RockPaperScissors::ALL.to_vec());
+        let markov = play_matchup(&Strategy::Markov, &cycle, &CONFIG, &mut Rng::new(1)).unwrap();
+        let frequency =
+            play_matchup(&Strategy::Frequency, &cycle, &CONFIG, &mut Rng::new(1)).unwrap();
+
+        assert!(markov.win_rate() > 0.9);
+        assert!(frequency.win_rate() < 0.5);
+    }
+
+    #[test]
+    fn test_from_guide() {
+        let rules = RuleSet::default();
+        let guide = super::super::parse("A Y\nB X\nC Z", &rules).unwrap();
+
+        assert_eq!(
+            Strategy::Fixed(vec![
+                RockPaperScissors::Rock,
+                RockPaperScissors::Rock,
+                RockPaperScissors::Rock
+            ]),
+            Strategy::from_guide(&guide, &rules, Interpretation::Part2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_guide_other_game() {
+        let rules = RuleSet::from_toml(
+            r#"
+moves = ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+
+[opponent]
+A = "Rock"
+E = "Scissors"
+
+[player]
+X = "Lizard"
+Z = "Scissors"
+"#,
+        )
+        .unwrap();
+        let guide = super::super::parse("A X\nE Z", &rules).unwrap();
+
+        assert_eq!(
+            Err(NOT_ROCK_PAPER_SCISSORS),
+            Strategy::from_guide(&guide, &rules, Interpretation::Part1)
+        );
+        assert_eq!(
+            None,
+            RockPaperScissors::from_move(rules.player("X").unwrap())
+        );
+
+        // scissors comes before paper and beats rock
+        let rules = RuleSet::from_toml(
+            r#"
+moves = ["Rock", "Scissors", "Paper"]
+
+[opponent]
+A = "Rock"
+B = "Paper"
+C = "Scissors"
+
+[player]
+X = "Rock"
+Y = "Paper"
+Z = "Scissors"
+"#,
+        )
+        .unwrap();
+        let guide = super::super::parse("A Y\nB X\nC Z", &rules).unwrap();
+
+        assert_eq!(
+            Err(NOT_ROCK_PAPER_SCISSORS),
+            Strategy::from_guide(&guide, &rules, Interpretation::Part1)
+        );
+    }
+
+    #[test]
+    fn test_tournament() {
+        let strategies = [
+            Strategy::Fixed(vec![RockPaperScissors::Paper]),
+            Strategy::Random,
+            Strategy::Frequency,
+            Strategy::Markov,
+        ];
+        let reports = tournament(&strategies, &CONFIG).unwrap();
+
+        assert_eq!(6, reports.len());
+        assert_eq!(reports, tournament(&strategies, &CONFIG).unwrap());
+        let random = &reports[0];
+        assert_eq!(("fixed", "random"), (random.player, random.opponent));
+        assert!((0.25..0.42).contains(&random.win_rate()));
+        assert_eq!(CONFIG.matches * CONFIG.rounds, random.rounds());
+        assert_eq!(
+            Err(EmptyTournament),
+            tournament(
+                &strategies,
+                &TournamentConfig {
+                    rounds: 0,
+                    ..CONFIG
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+use super::ValidItem;
+
+// an item set has one bit per item
+pub const MAX_ITEMS: usize = 64;
+
+pub static DEFAULT_ALPHABET: LazyLock<ItemAlphabet> = LazyLock::new(ItemAlphabet::default);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AlphabetError {
+    Empty,
+    TooManyItems(usize),
+    DuplicateItem(char),
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlphabetError::Empty => write!(f, "an alphabet needs at least one item"),
+            AlphabetError::TooManyItems(n) => {
+                write!(
+                    f,
+                    "an alphabet has at most {} items, found {}",
+                    MAX_ITEMS, n
+                )
+            }
+            AlphabetError::DuplicateItem(c) => write!(f, "item `{}` occurs more than once", c),
+        }
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+// the ordered characters that are valid items, by default an item has the priority of its
+// position in the alphabet, starting at 1
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ItemAlphabet {
+    items: Vec<ValidItem>,
+    // the index of every ascii item, so the puzzle alphabet does not need the map
+    ascii: [Option<u8>; 128],
+    others: HashMap<char, u8>,
+}
+
+impl ItemAlphabet {
+    pub fn new(chars: &str) -> Result<Self, AlphabetError> {
+        let mut alphabet = ItemAlphabet {
+            items: vec![],
+            ascii: [None; 128],
+            others: HashMap::new(),
+        };
+        for (idx, item) in chars.chars().enumerate() {
+            if alphabet.item(item).is_some() {
+                return Err(AlphabetError::DuplicateItem(item));
+            }
+            let index = u8::try_from(idx)
+                .ok()
+                .filter(|x| (*x as usize) < MAX_ITEMS)
+                .ok_or(AlphabetError::TooManyItems(chars.chars().count()))?;
+            match alphabet.ascii.get_mut(item as usize) {
+                Some(slot) => *slot = Some(index),
+                None => {
+                    alphabet.others.insert(item, index);
+                }
+            }
+            alphabet.items.push(ValidItem {
+                item,
+                index,
+                priority: idx as u32 + 1,
+            });
+        }
+
+        if alphabet.items.is_empty() {
+            return Err(AlphabetError::Empty);
+        }
+        Ok(alphabet)
+    }
+
+    // replaces the priorities with `priority(position, item)`, the position starting at 0
+    pub fn with_priorities(mut self, priority: impl Fn(usize, char) -> u32) -> Self {
+        for (idx, x) in self.items.iter_mut().enumerate() {
+            x.priority = priority(idx, x.item);
+        }
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn item(&self, c: char) -> Option<ValidItem> {
+        let index = match self.ascii.get(c as usize) {
+            Some(index) => *index,
+            None => self.others.get(&c).copied(),
+        };
+        index.map(|x| self.items[x as usize])
+    }
+
+    // the item at `index`, i.e. the bit of the item in an item set
+    pub fn get(&self, index: usize) -> Option<ValidItem> {
+        self.items.get(index).copied()
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = ValidItem> + '_ {
+        self.items.iter().copied()
+    }
+
+    pub fn expected(&self) -> String {
+        let chars: String = self.items.iter().map(|x| x.item).collect();
+        format!("an item of `{}`", chars)
+    }
+}
+
+// the puzzle alphabet: a to z have the priorities 1 to 26 and A to Z 27 to 52
+impl Default for ItemAlphabet {
+    fn default() -> Self {
+        let chars: String = ('a'..='z').chain('A'..='Z').collect();
+        ItemAlphabet::new(&chars).expect("the puzzle alphabet is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let alphabet = ItemAlphabet::default();
+
+        assert_eq!(52, alphabet.len());
+        assert_eq!(16, alphabet.item('p').unwrap().score());
+        assert_eq!(42, alphabet.item('P').unwrap().score());
+        assert_eq!(None, alphabet.item('1'));
+        assert_eq!(Some(alphabet.item('b').unwrap()), alphabet.get(1));
+    }
+
+    #[test]
+    fn test_custom() {
+        let alphabet = ItemAlphabet::new("0123456789éè")
+            .unwrap()
+            .with_priorities(|idx, c| if c.is_ascii_digit() { idx as u32 } else { 100 });
+
+        assert_eq!(12, alphabet.len());
+        assert_eq!(7, alphabet.item('7').unwrap().score());
+        assert_eq!(100, alphabet.item('é').unwrap().score());
+        assert_eq!(11, alphabet.item('è').unwrap().index);
+        assert_eq!(None, alphabet.item('e'));
+        assert_eq!("an item of `0123456789éè`", alphabet.expected());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Err(AlphabetError::Empty), ItemAlphabet::new(""));
+        assert_eq!(
+            Err(AlphabetError::DuplicateItem('a')),
+            ItemAlphabet::new("abca")
+        );
+
+        let chars: String = (0..65)
+            .map(|x| char::from_u32(0x100 + x).unwrap())
+            .collect();
+        assert_eq!(
+            Err(AlphabetError::TooManyItems(65)),
+            ItemAlphabet::new(&chars)
+        );
+        assert!(ItemAlphabet::new(&chars[..chars.len() - 2]).is_ok());
+    }
+}
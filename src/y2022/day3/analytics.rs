@@ -0,0 +1,332 @@
+use serde::Serialize;
+
+use super::alphabet::ItemAlphabet;
+use super::{check_group_size, ItemSet, Rucksack, ValidItem};
+use crate::csv;
+use crate::error::AocError;
+
+// `items` counts every occurrence of the item type, `compartments`, `rucksacks` and `groups`
+// the ones containing it at least once and `duplicated` the rucksacks with it in both
+// compartments
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+pub struct ItemCount {
+    pub item: char,
+    pub priority: u32,
+    pub items: usize,
+    pub compartments: usize,
+    pub rucksacks: usize,
+    pub groups: usize,
+    pub duplicated: usize,
+}
+
+impl ItemCount {
+    fn new(item: ValidItem) -> Self {
+        ItemCount {
+            item: item.item,
+            priority: item.score(),
+            items: 0,
+            compartments: 0,
+            rucksacks: 0,
+            groups: 0,
+            duplicated: 0,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize)]
+pub struct PriorityBucket {
+    pub priority: u32,
+    pub item: char,
+    pub items: usize,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct ItemStats {
+    pub rucksacks: usize,
+    pub groups: usize,
+    pub items: usize,
+    // the item types occurring in the input, in the order of the alphabet
+    pub counts: Vec<ItemCount>,
+    // the item types in both compartments of the most rucksacks, at most `top` of them
+    pub most_duplicated: Vec<ItemCount>,
+    // the number of items of every item type of the alphabet, including the ones not in the
+    // input
+    pub histogram: Vec<PriorityBucket>,
+}
+
+const HISTOGRAM_WIDTH: usize = 40;
+
+impl ItemStats {
+    // `alphabet` has to be the alphabet the rucksacks were parsed with, the groups have
+    // `group_size` rucksacks like in part 2
+    pub fn new(
+        rucksacks: &[Rucksack],
+        alphabet: &ItemAlphabet,
+        group_size: usize,
+        top: usize,
+    ) -> Result<Self, AocError> {
+        check_group_size(group_size)?;
+        if rucksacks.is_empty() {
+            return Err(AocError::NoSolution("there are no rucksacks"));
+        }
+
+        let mut counts: Vec<_> = alphabet.items().map(ItemCount::new).collect();
+        let mut count = |set: ItemSet, field: fn(&mut ItemCount) -> &mut usize| {
+            set.iter(alphabet)
+                .for_each(|x| *field(&mut counts[x.index as usize]) += 1)
+        };
+        for rucksack in rucksacks {
+            let (comp1, comp2) = (rucksack.comp1.items(), rucksack.comp2.items());
+            count(comp1, |x| &mut x.compartments);
+            count(comp2, |x| &mut x.compartments);
+            count(comp1.union(comp2), |x| &mut x.rucksacks);
+            count(comp1.intersection(comp2), |x| &mut x.duplicated);
+        }
+        for group in rucksacks.chunks(group_size) {
+            let items = group
+                .iter()
+                .map(|x| x.items())
+                .fold(ItemSet::new(), ItemSet::union);
+            count(items, |x| &mut x.groups);
+        }
+        for item in rucksacks
+            .iter()
+            .flat_map(|x| x.comp1.0.iter().chain(&x.comp2.0))
+        {
+            counts[item.index as usize].items += 1;
+        }
+
+        // ordered by priority, the alphabet order on ties
+        let mut histogram: Vec<_> = counts
+            .iter()
+            .map(|x| PriorityBucket {
+                priority: x.priority,
+                item: x.item,
+                items: x.items,
+            })
+            .collect();
+        histogram.sort_by_key(|x| x.priority);
+        counts.retain(|x| x.items > 0);
+        let mut most_duplicated: Vec<_> = counts.iter().filter(|x| x.duplicated > 0).collect();
+        most_duplicated.sort_by_key(|x| std::cmp::Reverse(x.duplicated));
+
+        Ok(ItemStats {
+            rucksacks: rucksacks.len(),
+            groups: rucksacks.len().div_ceil(group_size),
+            items: counts.iter().map(|x| x.items).sum(),
+            most_duplicated: most_duplicated.into_iter().take(top).copied().collect(),
+            counts,
+            histogram,
+        })
+    }
+
+    pub fn render_table(&self) -> String {
+        let mut table = format!(
+            "rucksacks: {}\ngroups: {}\nitems: {}\n",
+            self.rucksacks, self.groups, self.items
+        );
+
+        table.push_str(
+            "\nitem | priority | items | compartments | rucksacks | groups | duplicated\n",
+        );
+        for x in &self.counts {
+            table.push_str(&format!(
+                "{:>4} | {:>8} | {:>5} | {:>12} | {:>9} | {:>6} | {:>10}\n",
+                x.item, x.priority, x.items, x.compartments, x.rucksacks, x.groups, x.duplicated
+            ));
+        }
+
+        table.push_str("\nmost duplicated (rucksacks with the item in both compartments):\n");
+        for x in &self.most_duplicated {
+            table.push_str(&format!("{}: {}\n", x.item, x.duplicated));
+        }
+
+        // the bars are scaled so the most common priority fills the full width
+        let max = self.histogram.iter().map(|x| x.items).max().unwrap_or(0);
+        table.push_str("\npriority | item | items\n");
+        for x in &self.histogram {
+            let bar = (x.items * HISTOGRAM_WIDTH).div_ceil(max.max(1));
+            let row = format!(
+                "{:>8} | {:>4} | {:>5} {}",
+                x.priority,
+                x.item,
+                x.items,
+                "#".repeat(bar)
+            );
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+
+    pub fn histogram_csv(&self) -> String {
+        let mut csv = csv::record(["priority", "item", "items"]);
+        for x in &self.histogram {
+            let fields = [
+                x.priority.to_string(),
+                x.item.to_string(),
+                x.items.to_string(),
+            ];
+            csv.push_str(&csv::record(fields));
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::alphabet::DEFAULT_ALPHABET;
+    use super::super::{parse, GROUP_SIZE};
+    use super::*;
+
+    const TEST_INPUT: &str = "abcA
+    aBcc
+    AbbZ
+    ZZ";
+
+    fn count(stats: &ItemStats, item: char) -> ItemCount {
+        *stats.counts.iter().find(|x| x.item == item).unwrap()
+    }
+
+    #[test]
+    fn test_item_stats() {
+        let stats = ItemStats::new(
+            &parse(TEST_INPUT).unwrap(),
+            &DEFAULT_ALPHABET,
+            GROUP_SIZE,
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(4, stats.rucksacks);
+        assert_eq!(2, stats.groups);
+        assert_eq!(14, stats.items);
+        assert_eq!(
+            "abcABZ",
+            stats.counts.iter().map(|x| x.item).collect::<String>()
+        );
+        assert_eq!(
+            ItemCount {
+                item: 'c',
+                priority: 3,
+                items: 3,
+                compartments: 2,
+                rucksacks: 2,
+                groups: 1,
+                duplicated: 0,
+            },
+            count(&stats, 'c')
+        );
+        assert_eq!(
+            ItemCount {
+                item: 'Z',
+                priority: 52,
+                items: 3,
+                compartments: 3,
+                rucksacks: 2,
+                groups: 2,
+                duplicated: 1,
+            },
+            count(&stats, 'Z')
+        );
+        assert_eq!(
+            vec!['b', 'Z'],
+            stats
+                .most_duplicated
+                .iter()
+                .map(|x| x.item)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(52, stats.histogram.len());
+        assert_eq!(
+            PriorityBucket {
+                priority: 2,
+                item: 'b',
+                items: 3
+            },
+            stats.histogram[1]
+        );
+        assert_eq!(0, stats.histogram[3].items);
+        assert_eq!(
+            Err(AocError::NoSolution("there are no rucksacks")),
+            ItemStats::new(&[], &DEFAULT_ALPHABET, GROUP_SIZE, 2)
+        );
+    }
+
+    #[test]
+    fn test_item_stats_group_size() {
+        let rucksacks = parse(TEST_INPUT).unwrap();
+        let stats = ItemStats::new(&rucksacks, &DEFAULT_ALPHABET, 2, 2).unwrap();
+
+        assert_eq!(2, stats.groups);
+        assert_eq!(
+            (1, 2),
+            (count(&stats, 'Z').groups, count(&stats, 'A').groups)
+        );
+        let stats = ItemStats::new(&rucksacks, &DEFAULT_ALPHABET, 1, 2).unwrap();
+        assert_eq!(4, stats.groups);
+        assert_eq!(2, count(&stats, 'Z').groups);
+        assert_eq!(
+            Err(AocError::NoSolution(
+                "elf groups need at least one rucksack"
+            )),
+            ItemStats::new(&rucksacks, &DEFAULT_ALPHABET, 0, 2)
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let table = ItemStats::new(
+            &parse("abca\nAB").unwrap(),
+            &DEFAULT_ALPHABET,
+            GROUP_SIZE,
+            1,
+        )
+        .unwrap()
+        .render_table();
+        let lines: Vec<_> = table.lines().collect();
+
+        assert_eq!(
+            vec![
+                "rucksacks: 2",
+                "groups: 1",
+                "items: 6",
+                "",
+                "item | priority | items | compartments | rucksacks | groups | duplicated",
+                "   a |        1 |     2 |            2 |         1 |      1 |          1",
+                "   b |        2 |     1 |            1 |         1 |      1 |          0",
+                "   c |        3 |     1 |            1 |         1 |      1 |          0",
+                "   A |       27 |     1 |            1 |         1 |      1 |          0",
+                "   B |       28 |     1 |            1 |         1 |      1 |          0",
+                "",
+                "most duplicated (rucksacks with the item in both compartments):",
+                "a: 1",
+                "",
+                "priority | item | items",
+                "       1 |    a |     2 ########################################",
+                "       2 |    b |     1 ####################",
+            ],
+            lines[..17]
+        );
+        assert_eq!("       4 |    d |     0", lines[18]);
+    }
+
+    #[test]
+    fn test_histogram_csv() {
+        let alphabet = ItemAlphabet::new("ab,\"").unwrap();
+        let rucksacks = super::super::parse_with_alphabet("a,\"a\nbb", &alphabet).unwrap();
+
+        assert_eq!(
+            "\
+priority,item,items
+1,a,2
+2,b,2
+3,\",\",1
+4,\"\"\"\",1
+",
+            ItemStats::new(&rucksacks, &alphabet, GROUP_SIZE, 1)
+                .unwrap()
+                .histogram_csv()
+        );
+    }
+}
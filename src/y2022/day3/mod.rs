@@ -0,0 +1,616 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{AocError, LocatedError};
+use crate::solution::Solution;
+
+pub mod alphabet;
+pub mod analytics;
+pub mod rebalance;
+
+use alphabet::{ItemAlphabet, DEFAULT_ALPHABET};
+
+// the number of elves in a group of part 2
+pub const GROUP_SIZE: usize = 3;
+
+// `position` is the position of the character in the rucksack, starting at 0, a `u32` keeps
+// `AocError` small
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidItem { position: u32 },
+    UnevenNumberOfItems,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidItem { .. } => write!(f, "invalid item"),
+            ParseError::UnevenNumberOfItems => write!(f, "rucksack has an uneven number of items"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BadgeErrorKind {
+    NoBadge,
+    AmbiguousBadge(Vec<ValidItem>),
+    IncompleteGroup { expected: usize },
+}
+
+// `group` is the position of the group in the input, starting at 0, `lines` are the line numbers
+// of its rucksacks and `rucksacks` their items
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BadgeError {
+    pub group: usize,
+    pub lines: RangeInclusive<usize>,
+    pub rucksacks: Vec<String>,
+    pub kind: BadgeErrorKind,
+}
+
+impl fmt::Display for BadgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "elf group {} (lines {}-{}) ",
+            self.group,
+            self.lines.start(),
+            self.lines.end()
+        )?;
+        match &self.kind {
+            BadgeErrorKind::NoBadge => write!(f, "has no badge")?,
+            BadgeErrorKind::AmbiguousBadge(candidates) => {
+                let candidates: Vec<_> = candidates.iter().map(|x| format!("`{}`", x)).collect();
+                write!(f, "has multiple badge candidates {}", candidates.join(", "))?
+            }
+            BadgeErrorKind::IncompleteGroup { expected } => write!(
+                f,
+                "is incomplete: expected {} rucksacks, found {}",
+                expected,
+                self.rucksacks.len()
+            )?,
+        }
+        for (line, rucksack) in self.lines.clone().zip(&self.rucksacks) {
+            write!(f, "\n  line {}: {}", line, rucksack)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BadgeError {}
+
+// `index` is the position of the item in its alphabet
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+pub struct ValidItem {
+    item: char,
+    index: u8,
+    priority: u32,
+}
+
+impl ValidItem {
+    pub fn item(&self) -> char {
+        self.item
+    }
+
+    pub fn score(&self) -> u32 {
+        self.priority
+    }
+}
+
+impl fmt::Display for ValidItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.item)
+    }
+}
+
+// the distinct items, in the order of their alphabet
+fn distinct<'a>(items: impl IntoIterator<Item = &'a ValidItem>) -> Vec<ValidItem> {
+    let mut seen = ItemSet::new();
+    let mut distinct: Vec<_> = items
+        .into_iter()
+        .filter(|x| {
+            let new = !seen.contains(**x);
+            seen.insert(**x);
+            new
+        })
+        .copied()
+        .collect();
+    distinct.sort_unstable_by_key(|x| x.index);
+    distinct
+}
+
+// bit `n` is set if the item at index `n` of the alphabet is in the set
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> Self {
+        ItemSet(0)
+    }
+
+    pub fn insert(&mut self, item: ValidItem) {
+        self.0 |= 1 << item.index;
+    }
+
+    pub fn contains(&self, item: ValidItem) -> bool {
+        self.0 & (1 << item.index) != 0
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // in the order of `alphabet`, which has to be the alphabet of the items in the set
+    pub fn iter<'a>(&self, alphabet: &'a ItemAlphabet) -> impl Iterator<Item = ValidItem> + 'a {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let idx = bits.trailing_zeros();
+            bits &= bits - 1;
+            alphabet.get(idx as usize)
+        })
+    }
+
+    // the sum of the scores of all items in the set
+    pub fn score(&self, alphabet: &ItemAlphabet) -> u32 {
+        self.iter(alphabet).map(|x| x.score()).sum()
+    }
+}
+
+impl FromIterator<ValidItem> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = ValidItem>>(iter: T) -> Self {
+        let mut set = ItemSet::new();
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Compartment(Vec<ValidItem>);
+
+impl Compartment {
+    fn items(&self) -> ItemSet {
+        self.0.iter().copied().collect()
+    }
+
+    // the items in the order of the input
+    pub fn iter(&self) -> impl Iterator<Item = &ValidItem> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn count(&self, item: ValidItem) -> usize {
+        self.0.iter().filter(|x| **x == item).count()
+    }
+}
+
+impl fmt::Display for Compartment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|x| write!(f, "{}", x))
+    }
+}
+
+impl Compartment {
+    pub fn parse(s: &str, alphabet: &ItemAlphabet) -> Result<Self, ParseError> {
+        let items = s
+            .chars()
+            .enumerate()
+            .map(|(position, item)| {
+                alphabet.item(item).ok_or(ParseError::InvalidItem {
+                    position: position as u32,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Compartment(items))
+    }
+}
+
+impl FromStr for Compartment {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Compartment::parse(s, &DEFAULT_ALPHABET)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    comp1: Compartment,
+    comp2: Compartment,
+}
+
+impl Rucksack {
+    pub fn parse(s: &str, alphabet: &ItemAlphabet) -> Result<Self, ParseError> {
+        let len = s.chars().count();
+        if !len.is_multiple_of(2) {
+            return Err(ParseError::UnevenNumberOfItems);
+        }
+
+        let mid = s
+            .char_indices()
+            .nth(len / 2)
+            .map_or(s.len(), |(idx, _)| idx);
+        let (comp1, comp2) = s.split_at(mid);
+        Ok(Rucksack {
+            comp1: Compartment::parse(comp1, alphabet)?,
+            comp2: Compartment::parse(comp2, alphabet).map_err(|e| match e {
+                ParseError::InvalidItem { position } => ParseError::InvalidItem {
+                    position: position + (len / 2) as u32,
+                },
+                e => e,
+            })?,
+        })
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rucksack::parse(s, &DEFAULT_ALPHABET)
+    }
+}
+
+impl fmt::Display for Rucksack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.comp1, self.comp2)
+    }
+}
+
+impl Rucksack {
+    pub fn comp1(&self) -> &Compartment {
+        &self.comp1
+    }
+
+    pub fn comp2(&self) -> &Compartment {
+        &self.comp2
+    }
+
+    fn common_item(&self) -> Option<ValidItem> {
+        let common = self.comp1.items().intersection(self.comp2.items());
+        self.comp2.0.iter().find(|x| common.contains(**x)).copied()
+    }
+
+    fn items(&self) -> ItemSet {
+        self.comp1.items().union(self.comp2.items())
+    }
+}
+
+#[derive(Debug)]
+struct ElfGroup<'a>(Vec<&'a Rucksack>);
+
+impl<'a> ElfGroup<'a> {
+    fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = &'a Rucksack>,
+    {
+        ElfGroup(iter.into_iter().collect())
+    }
+
+    // the badge is the only item carried by every elf of the group
+    fn find_badge(&self) -> Result<ValidItem, BadgeErrorKind> {
+        let candidates = self
+            .0
+            .iter()
+            .map(|x| x.items())
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+
+        let items = self
+            .0
+            .iter()
+            .flat_map(|x| x.comp1.0.iter().chain(&x.comp2.0));
+        let candidates = distinct(items.filter(|x| candidates.contains(**x)));
+        match candidates[..] {
+            [] => Err(BadgeErrorKind::NoBadge),
+            [badge] => Ok(badge),
+            _ => Err(BadgeErrorKind::AmbiguousBadge(candidates)),
+        }
+    }
+}
+
+pub fn parse_with_alphabet(
+    input: &str,
+    alphabet: &ItemAlphabet,
+) -> Result<Vec<Rucksack>, AocError> {
+    input
+        .lines()
+        .map(str::trim)
+        .map(|x| {
+            Rucksack::parse(x, alphabet).map_err(|e| {
+                let diagnostic = match e {
+                    ParseError::InvalidItem { position } => {
+                        let found = x
+                            .char_indices()
+                            .nth(position as usize)
+                            .map_or(x, |(idx, c)| &x[idx..idx + c.len_utf8()]);
+                        Diagnostic::new(input, found, alphabet.expected())
+                    }
+                    ParseError::UnevenNumberOfItems => {
+                        Diagnostic::new(input, x, "an even number of items")
+                    }
+                };
+                e.at(diagnostic)
+            })
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, AocError> {
+    parse_with_alphabet(input, &DEFAULT_ALPHABET)
+}
+
+pub fn part1(input: &[Rucksack]) -> Result<u32, AocError> {
+    input
+        .iter()
+        .map(|x| {
+            x.common_item()
+                .map(|item| item.score())
+                .ok_or(AocError::NoSolution("rucksack without common item"))
+        })
+        .sum()
+}
+
+// the rucksacks are expected to be in the order of the input, one per line
+fn badges(
+    input: &[Rucksack],
+    group_size: usize,
+) -> impl Iterator<Item = Result<ValidItem, BadgeError>> + '_ {
+    input.chunks(group_size).enumerate().map(move |(idx, x)| {
+        let group = ElfGroup::new(x);
+        let badge = if x.len() < group_size {
+            Err(BadgeErrorKind::IncompleteGroup {
+                expected: group_size,
+            })
+        } else {
+            group.find_badge()
+        };
+        badge.map_err(|kind| BadgeError {
+            group: idx,
+            lines: idx * group_size + 1..=idx * group_size + x.len(),
+            rucksacks: x.iter().map(|x| x.to_string()).collect(),
+            kind,
+        })
+    })
+}
+
+fn check_group_size(group_size: usize) -> Result<(), AocError> {
+    if group_size == 0 {
+        return Err(AocError::NoSolution(
+            "elf groups need at least one rucksack",
+        ));
+    }
+    Ok(())
+}
+
+pub fn part2_with_group_size(input: &[Rucksack], group_size: usize) -> Result<u32, AocError> {
+    check_group_size(group_size)?;
+    badges(input, group_size)
+        .map(|x| x.map(|item| item.score()).map_err(AocError::Badge))
+        .sum()
+}
+
+pub fn part2(input: &[Rucksack]) -> Result<u32, AocError> {
+    part2_with_group_size(input, GROUP_SIZE)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 3;
+    type Input = Vec<Rucksack>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    const TEST_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+    jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+    PmmdzqPrVvPwwTWBwg
+    wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+    ttgJtRGJQctTZtZT
+    CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![
+                Rucksack {
+                    comp1: Compartment::from_str("vJrwpWtwJgWr").unwrap(),
+                    comp2: Compartment::from_str("hcsFMMfFFhFp").unwrap(),
+                },
+                Rucksack {
+                    comp1: Compartment::from_str("jqHRNqRjqzjGDLGL").unwrap(),
+                    comp2: Compartment::from_str("rsFMfFZSrLrFZsSL").unwrap(),
+                },
+                Rucksack {
+                    comp1: Compartment::from_str("PmmdzqPrV").unwrap(),
+                    comp2: Compartment::from_str("vPwwTWBwg").unwrap(),
+                },
+                Rucksack {
+                    comp1: Compartment::from_str("wMqvLMZHhHMvwLH").unwrap(),
+                    comp2: Compartment::from_str("jbvcjnnSBnvTQFn").unwrap(),
+                },
+                Rucksack {
+                    comp1: Compartment::from_str("ttgJtRGJ").unwrap(),
+                    comp2: Compartment::from_str("QctTZtZT").unwrap(),
+                },
+                Rucksack {
+                    comp1: Compartment::from_str("CrZsJsPPZsGz").unwrap(),
+                    comp2: Compartment::from_str("wwsLwLmpwMDw").unwrap(),
+                }
+            ],
+            parse(TEST_INPUT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("abAB\nab1B").unwrap_err();
+        assert_eq!(
+            "\
+error: invalid item
+  --> line 2, column 3
+  |
+2 | ab1B
+  |   ^ expected an item of `abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ`, found `1`",
+            format!("{:#}", err)
+        );
+        assert!(matches!(
+            parse("abAB\nabA1").unwrap_err(),
+            AocError::Rucksack(_, ParseError::InvalidItem { position: 3 })
+        ));
+
+        let err = parse("abA").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Rucksack(_, ParseError::UnevenNumberOfItems)
+        ));
+        assert_eq!(0..3, err.diagnostic().unwrap().span);
+    }
+
+    #[test]
+    fn test_parse_with_alphabet() {
+        let alphabet = ItemAlphabet::new("0123456789äöü").unwrap();
+        let input = "12ä3ä4\nü1ä1\n5ä6ä";
+        let rucksacks = parse_with_alphabet(input, &alphabet).unwrap();
+
+        assert_eq!("12ä", rucksacks[0].comp1.to_string());
+        assert_eq!("3ä4", rucksacks[0].comp2.to_string());
+        assert_eq!(11 + 2 + 11, part1(&rucksacks).unwrap());
+        assert_eq!(11, part2(&rucksacks).unwrap());
+
+        let err = parse_with_alphabet("12ä3a4", &alphabet).unwrap_err();
+        assert_eq!(
+            "invalid item at line 1, column 5: expected an item of `0123456789äöü`, found `a`",
+            err.to_string()
+        );
+        assert!(parse_with_alphabet("12ä", &alphabet).is_err());
+    }
+
+    #[test]
+    fn test_item_set() {
+        let items = |s: &str| Compartment::from_str(s).unwrap().items();
+        let set = items("vJrwpWtwJgWr");
+
+        assert_eq!(8, set.len());
+        assert!(set.contains(DEFAULT_ALPHABET.item('J').unwrap()));
+        assert!(!set.contains(DEFAULT_ALPHABET.item('j').unwrap()));
+        assert_eq!(
+            "gprtvwJW",
+            set.iter(&DEFAULT_ALPHABET)
+                .map(|x| x.item)
+                .collect::<String>()
+        );
+        assert_eq!(
+            items("pr"),
+            set.intersection(items("hcsFMMfFFhFp")).union(items("r"))
+        );
+        assert_eq!(16 + 18, items("pr").score(&DEFAULT_ALPHABET));
+        assert!(set.intersection(items("ab")).is_empty());
+        assert!(ItemSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_part2_with_group_size() {
+        let input = parse(TEST_INPUT).unwrap();
+
+        assert_eq!(70, part2_with_group_size(&input, 3).unwrap());
+        assert_eq!(
+            AocError::Badge(BadgeError {
+                group: 0,
+                lines: 1..=2,
+                rucksacks: vec![
+                    "vJrwpWtwJgWrhcsFMMfFFhFp".to_string(),
+                    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".to_string()
+                ],
+                kind: BadgeErrorKind::AmbiguousBadge(
+                    "frsFM"
+                        .chars()
+                        .map(|c| DEFAULT_ALPHABET.item(c).unwrap())
+                        .collect()
+                ),
+            }),
+            part2_with_group_size(&input, 2).unwrap_err()
+        );
+        assert!(part2_with_group_size(&input, 0).is_err());
+    }
+
+    #[test]
+    fn test_badge_errors() {
+        let input = parse("abcd\nefgh\nijab\nabAB").unwrap();
+        let errors: Vec<_> = badges(&input, 3).filter_map(Result::err).collect();
+
+        assert_eq!(2, errors.len());
+        assert_eq!(
+            "elf group 0 (lines 1-3) has no badge\n  line 1: abcd\n  line 2: efgh\n  line 3: ijab",
+            errors[0].to_string()
+        );
+        assert_eq!(
+            "elf group 1 (lines 4-4) is incomplete: expected 3 rucksacks, found 1\n  line 4: abAB",
+            errors[1].to_string()
+        );
+        assert_eq!(
+            "elf group 0 (lines 1-2) has multiple badge candidates `a`, `b`",
+            badges(&input[2..], 2)
+                .next()
+                .unwrap()
+                .unwrap_err()
+                .to_string()
+                .lines()
+                .next()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(157, part1(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(70, part2(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,170 @@
+use super::{distinct, Compartment, ItemSet, Rucksack, ValidItem};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Side {
+    Comp1,
+    Comp2,
+}
+
+// `position` is the index of the item in the compartment it is moved out of
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ItemMove {
+    pub item: ValidItem,
+    pub from: Side,
+    pub position: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rebalanced {
+    pub comp1: Compartment,
+    pub comp2: Compartment,
+    pub moves: Vec<ItemMove>,
+    pub cost: u32,
+}
+
+impl Rucksack {
+    // the fewest moves, and of those the ones with the lowest sum of priorities, after which no
+    // item type is in both compartments while both keep their size, none if there are no such
+    // moves
+    pub fn rebalance(&self) -> Option<Rebalanced> {
+        let in_comp1 = self.assign_types()?;
+
+        let out1 = self.comp1.0.iter().enumerate();
+        let out1 = out1.filter(|(_, x)| !in_comp1.contains(**x));
+        let out2 = self.comp2.0.iter().enumerate();
+        let out2 = out2.filter(|(_, x)| in_comp1.contains(**x));
+
+        // the compartments keep their size, so every item leaving one takes the place of an
+        // item leaving the other one
+        let mut comp1 = self.comp1.0.clone();
+        let mut comp2 = self.comp2.0.clone();
+        let mut moves = vec![];
+        for ((pos1, item1), (pos2, item2)) in out1.zip(out2) {
+            comp1[pos1] = *item2;
+            comp2[pos2] = *item1;
+            moves.push(ItemMove {
+                item: *item1,
+                from: Side::Comp1,
+                position: pos1,
+            });
+            moves.push(ItemMove {
+                item: *item2,
+                from: Side::Comp2,
+                position: pos2,
+            });
+        }
+
+        Some(Rebalanced {
+            comp1: Compartment(comp1),
+            comp2: Compartment(comp2),
+            cost: moves.iter().map(|x| x.item.score()).sum(),
+            moves,
+        })
+    }
+
+    // picks the compartment of every item type with a subset sum over the type counts, returns
+    // the types ending up in comp1
+    fn assign_types(&self) -> Option<ItemSet> {
+        let types: Vec<_> = distinct(self.comp1.0.iter().chain(&self.comp2.0))
+            .into_iter()
+            .map(|x| (x, self.comp1.count(x), self.comp2.count(x)))
+            .collect();
+        let size = self.comp1.len();
+
+        // best[i][n]: fewest (moves, cost) placing the first i types with n items in comp1
+        let mut best = vec![vec![None; size + 1]; types.len() + 1];
+        best[0][0] = Some((0, 0));
+        for (i, &(item, count1, count2)) in types.iter().enumerate() {
+            let priority = item.score();
+            for n in 0..=size {
+                let Some((moves, cost)) = best[i][n] else {
+                    continue;
+                };
+                let options = [(n + count1 + count2, count2), (n, count1)];
+                for (next, moved) in options {
+                    let candidate = (moves + moved, cost + moved as u32 * priority);
+                    if next <= size && best[i + 1][next].is_none_or(|x| candidate < x) {
+                        best[i + 1][next] = Some(candidate);
+                    }
+                }
+            }
+        }
+
+        best[types.len()][size]?;
+        let mut in_comp1 = ItemSet::new();
+        let mut n = size;
+        for (i, &(item, count1, count2)) in types.iter().enumerate().rev() {
+            let priority = item.score();
+            let kept_in_comp1 = n
+                .checked_sub(count1 + count2)
+                .and_then(|prev| best[i][prev])
+                .map(|(moves, cost)| (moves + count2, cost + count2 as u32 * priority));
+            if kept_in_comp1.is_some() && kept_in_comp1 == best[i + 1][n] {
+                in_comp1.insert(item);
+                n -= count1 + count2;
+            }
+        }
+        Some(in_comp1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::super::alphabet::DEFAULT_ALPHABET;
+    use super::*;
+
+    fn rucksack(s: &str) -> Rucksack {
+        Rucksack::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let rebalanced = rucksack("vJrwpWtwJgWrhcsFMMfFFhFp").rebalance().unwrap();
+
+        assert_eq!("vJrwcWtwJgWr", rebalanced.comp1.to_string());
+        assert_eq!("hpsFMMfFFhFp", rebalanced.comp2.to_string());
+        assert_eq!(
+            vec![
+                ItemMove {
+                    item: DEFAULT_ALPHABET.item('p').unwrap(),
+                    from: Side::Comp1,
+                    position: 4
+                },
+                ItemMove {
+                    item: DEFAULT_ALPHABET.item('c').unwrap(),
+                    from: Side::Comp2,
+                    position: 1
+                }
+            ],
+            rebalanced.moves
+        );
+        assert_eq!(16 + 3, rebalanced.cost);
+    }
+
+    #[test]
+    fn test_rebalance_cheapest() {
+        // every solution takes two moves, swapping `a` and `b` has the lowest priorities
+        let rebalanced = rucksack("aZbcYa").rebalance().unwrap();
+
+        assert_eq!("aZa", rebalanced.comp1.to_string());
+        assert_eq!("cYb", rebalanced.comp2.to_string());
+        assert_eq!(1 + 2, rebalanced.cost);
+    }
+
+    #[test]
+    fn test_rebalance_unchanged() {
+        let rebalanced = rucksack("abcdef").rebalance().unwrap();
+
+        assert_eq!("abc", rebalanced.comp1.to_string());
+        assert_eq!("def", rebalanced.comp2.to_string());
+        assert!(rebalanced.moves.is_empty());
+        assert_eq!(0, rebalanced.cost);
+    }
+
+    #[test]
+    fn test_rebalance_impossible() {
+        assert_eq!(None, rucksack("aaab").rebalance());
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{AocError, LocatedError};
+use crate::interval::Interval;
+use crate::solution::Solution;
+
+// the sections of an elf, `a-b` in the input
+pub type Range = Interval<usize>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidRangeFormat,
+    DecreasingRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidRangeFormat => write!(f, "invalid range"),
+            ParseError::DecreasingRange => write!(f, "range ends before it starts"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_range(s: &str) -> Result<Range, ParseError> {
+    let (a, b) = s.split_once('-').ok_or(ParseError::InvalidRangeFormat)?;
+    let a = a.parse().map_err(|_| ParseError::InvalidRangeFormat)?;
+    let b = b.parse().map_err(|_| ParseError::InvalidRangeFormat)?;
+
+    if a > b {
+        return Err(ParseError::DecreasingRange);
+    }
+    Ok(Interval::closed(a, b))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Range, Range)>, AocError> {
+    let parse_range = |x: &str| {
+        parse_range(x).map_err(|e| {
+            let expected = match e {
+                ParseError::InvalidRangeFormat => "a range like `2-4`",
+                ParseError::DecreasingRange => "a start not greater than the end",
+            };
+            e.at(Diagnostic::new(input, x, expected))
+        })
+    };
+
+    input
+        .lines()
+        .map(str::trim)
+        .map(|x| {
+            x.split_once(',').ok_or_else(|| {
+                AocError::InvalidFormat(Diagnostic::new(input, x, "`<range>,<range>`"))
+            })
+        })
+        .map(|line| {
+            let (a, b) = line?;
+            Ok((parse_range(a.trim())?, parse_range(b.trim())?))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(Range, Range)]) -> Result<usize, AocError> {
+    Ok(input
+        .iter()
+        .filter(|(a, b)| a.is_subset(b) || b.is_subset(a))
+        .count())
+}
+
+pub fn part2(input: &[(Range, Range)]) -> Result<usize, AocError> {
+    Ok(input.iter().filter(|(a, b)| a.overlaps(b)).count())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 4;
+    type Input = Vec<(Range, Range)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "2-4,6-8
+    2-3,4-5
+    5-7,7-9
+    2-8,3-7
+    6-6,4-6
+    2-6,4-8";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![
+                (Range::closed(2, 4), Range::closed(6, 8)),
+                (Range::closed(2, 3), Range::closed(4, 5)),
+                (Range::closed(5, 7), Range::closed(7, 9)),
+                (Range::closed(2, 8), Range::closed(3, 7)),
+                (Range::closed(6, 6), Range::closed(4, 6)),
+                (Range::closed(2, 6), Range::closed(4, 8))
+            ],
+            parse(TEST_INPUT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2-4,6-8\n2-3,4_5").unwrap_err();
+        assert_eq!(
+            "\
+error: invalid range
+  --> line 2, column 5
+  |
+2 | 2-3,4_5
+  |     ^^^ expected a range like `2-4`, found `4_5`",
+            format!("{:#}", err)
+        );
+
+        let err = parse("2-4,8-6").unwrap_err();
+        assert_eq!(
+            "range ends before it starts at line 1, column 5: expected a start not greater than the end, found `8-6`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(2, part1(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(4, part2(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,353 @@
+use core::num::ParseIntError;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::{AocError, LocatedError};
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Stack(Vec<char>);
+
+impl Stack {
+    fn new() -> Self {
+        Stack(vec![])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Stacks(Vec<Stack>);
+
+impl Stacks {
+    fn stack_mut(&mut self, number: usize) -> Result<&mut Stack, AocError> {
+        number
+            .checked_sub(1)
+            .and_then(|idx| self.0.get_mut(idx))
+            .ok_or(AocError::NoSolution(
+                "instruction refers to a missing stack",
+            ))
+    }
+
+    fn run_instruction(
+        &mut self,
+        instruction: &Instruction,
+        is_mover_9001: bool,
+    ) -> Result<(), AocError> {
+        match instruction {
+            Instruction::Move(mv) => {
+                if is_mover_9001 {
+                    let from = self.stack_mut(mv.from)?;
+                    let start = from
+                        .0
+                        .len()
+                        .checked_sub(mv.count)
+                        .ok_or(AocError::NoSolution("failed to pop items"))?;
+                    let drained: Vec<_> = from.0.drain(start..).collect();
+
+                    let to = self.stack_mut(mv.to)?;
+                    drained.iter().for_each(|x| to.0.push(*x));
+                } else {
+                    for _ in 0..mv.count {
+                        let from = self.stack_mut(mv.from)?;
+                        let value = from
+                            .0
+                            .pop()
+                            .ok_or(AocError::NoSolution("failed to pop items"))?;
+
+                        let to = self.stack_mut(mv.to)?;
+                        to.0.push(value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn top_items(mut self) -> Result<String, AocError> {
+        self.0
+            .iter_mut()
+            .map(|x| x.0.pop().ok_or(AocError::NoSolution("empty stack")))
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct MoveArguments {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Instruction {
+    Move(MoveArguments),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InstructionParseError {
+    InvalidInstruction,
+}
+
+impl fmt::Display for InstructionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstructionParseError::InvalidInstruction => write!(f, "invalid instruction"),
+        }
+    }
+}
+
+impl std::error::Error for InstructionParseError {}
+
+impl From<ParseIntError> for InstructionParseError {
+    fn from(_: ParseIntError) -> Self {
+        Self::InvalidInstruction
+    }
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = InstructionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instruction_args: Vec<_> = s.split(' ').collect();
+        match instruction_args[..] {
+            ["move", count, "from", from, "to", to] => {
+                let count = count.parse()?;
+                let from = from.parse()?;
+                let to = to.parse()?;
+
+                Ok(Instruction::Move(MoveArguments { count, from, to }))
+            }
+            _ => Err(InstructionParseError::InvalidInstruction),
+        }
+    }
+}
+
+fn invalid_instruction_token(line: &str) -> (&str, &'static str) {
+    const EXPECTED: [(Option<&str>, &str); 6] = [
+        (Some("move"), "`move`"),
+        (None, "a crate count"),
+        (Some("from"), "`from`"),
+        (None, "a stack number"),
+        (Some("to"), "`to`"),
+        (None, "a stack number"),
+    ];
+
+    let mut tokens = line.split(' ');
+    for (keyword, expected) in EXPECTED {
+        match tokens.next() {
+            Some(token) if keyword.map_or(token.parse::<usize>().is_ok(), |k| k == token) => {}
+            Some(token) => return (token, expected),
+            None => return (&line[line.len()..], expected),
+        }
+    }
+
+    (tokens.next().unwrap_or(line), "end of line")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CratePlan {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+fn parse_stack_drawing<'a, I>(mut stack_drawing: I) -> Option<Stacks>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let stack_num = stack_drawing
+        .next()?
+        .split_ascii_whitespace()
+        .skip_while(|x| x.is_empty())
+        .count();
+
+    let mut stacks: Stacks = Stacks(Vec::with_capacity(stack_num));
+    stacks.0.resize_with(stack_num, Stack::new);
+
+    for line in stack_drawing {
+        for (idx, value) in line
+            .chars()
+            .skip(1)
+            .step_by(4)
+            .enumerate()
+            .filter(|(_, x)| !x.is_ascii_whitespace())
+        {
+            stacks.0.get_mut(idx)?.0.push(value);
+        }
+    }
+
+    Some(stacks)
+}
+
+pub fn parse(input: &str) -> Result<CratePlan, AocError> {
+    let mut lines = input.lines();
+    let stack_drawing: Vec<_> = lines
+        .by_ref()
+        .take_while(|x| !x.trim().is_empty())
+        .collect();
+
+    let stacks = parse_stack_drawing(stack_drawing.iter().copied().rev()).ok_or_else(|| {
+        AocError::InvalidFormat(Diagnostic::new(
+            input,
+            stack_drawing.last().copied().unwrap_or(input),
+            "a stack drawing with numbered stacks",
+        ))
+    })?;
+
+    let instructions = lines
+        .map(|x| {
+            Instruction::from_str(x).map_err(|e| {
+                let (found, expected) = invalid_instruction_token(x);
+                e.at(Diagnostic::new(input, found, expected))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(CratePlan {
+        stacks,
+        instructions,
+    })
+}
+
+pub fn part1(input: &CratePlan) -> Result<String, AocError> {
+    let mut stacks = input.stacks.clone();
+    input
+        .instructions
+        .iter()
+        .try_for_each(|i| stacks.run_instruction(i, false))?;
+
+    stacks.top_items()
+}
+
+pub fn part2(input: &CratePlan) -> Result<String, AocError> {
+    let mut stacks = input.stacks.clone();
+    input
+        .instructions
+        .iter()
+        .try_for_each(|i| stacks.run_instruction(i, true))?;
+
+    stacks.top_items()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 5;
+    type Input = CratePlan;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+\x20\x20\x20\x20[D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            CratePlan {
+                stacks: Stacks(vec![
+                    Stack(vec!['Z', 'N']),
+                    Stack(vec!['M', 'C', 'D']),
+                    Stack(vec!['P'])
+                ]),
+                instructions: vec![
+                    Instruction::Move(MoveArguments {
+                        count: 1,
+                        from: 2,
+                        to: 1
+                    }),
+                    Instruction::Move(MoveArguments {
+                        count: 3,
+                        from: 1,
+                        to: 3
+                    }),
+                    Instruction::Move(MoveArguments {
+                        count: 2,
+                        from: 2,
+                        to: 1
+                    }),
+                    Instruction::Move(MoveArguments {
+                        count: 1,
+                        from: 1,
+                        to: 2
+                    }),
+                ]
+            },
+            parse(TEST_INPUT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[A] [B]\n 1   2 \n\nmove 1 from 2 onto 1").unwrap_err();
+        assert_eq!(
+            "\
+error: invalid instruction
+  --> line 4, column 15
+  |
+4 | move 1 from 2 onto 1
+  |               ^^^^ expected `to`, found `onto`",
+            format!("{:#}", err)
+        );
+
+        let err = parse("[A] [B]\n 1   2 \n\nmove 1 from").unwrap_err();
+        assert_eq!(
+            "invalid instruction at line 4, column 12: expected a stack number, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_instruction() {
+        assert_eq!(
+            Instruction::from_str("move 3 from 1 to 3"),
+            Ok(Instruction::Move(MoveArguments {
+                count: 3,
+                from: 1,
+                to: 3
+            }))
+        );
+
+        assert_eq!(
+            Instruction::from_str(""),
+            Err(InstructionParseError::InvalidInstruction)
+        );
+
+        assert_eq!(
+            Instruction::from_str("move a from 1 to 2"),
+            Err(InstructionParseError::InvalidInstruction)
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("CMZ", part1(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("MCD", part2(&parse(TEST_INPUT).unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,81 @@
+use itertools::Itertools;
+
+use crate::error::AocError;
+use crate::solution::Solution;
+
+fn find_unique_window(input: &str, window_size: usize) -> Result<usize, AocError> {
+    let windows = input.as_bytes().windows(window_size);
+
+    windows
+        .enumerate()
+        .find(|(_idx, x)| x.iter().unique().count() == window_size)
+        .map(|(idx, _)| idx + window_size)
+        .ok_or(AocError::NoSolution("no window of unique characters"))
+}
+
+pub fn part1(input: &str) -> Result<usize, AocError> {
+    find_unique_window(input, 4)
+}
+
+pub fn part2(input: &str) -> Result<usize, AocError> {
+    find_unique_window(input, 14)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 6;
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: [&str; 5] = [
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+        "bvwbjplbgvbhsrlpgdmjqwftvncz",
+        "nppdvjthqldpwncqszvftbrmjlhg",
+        "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+        "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+    ];
+
+    #[test]
+    fn test_parse() {}
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            vec![7, 5, 6, 10, 11],
+            TEST_INPUT
+                .iter()
+                .map(|x| part1(x).unwrap())
+                .collect::<Vec<_>>()
+        )
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            vec![19, 23, 23, 29, 26],
+            TEST_INPUT
+                .iter()
+                .map(|x| part2(x).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+}